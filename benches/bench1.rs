@@ -795,6 +795,16 @@ mat_mul! {mat_mul_i32, i32,
     (m032, 32, 32, 32)
     (m064, 64, 64, 64)
     (m127, 127, 127, 127)
+    (m256, 256, 256, 256)
+    (mix16x4, 32, 4, 32)
+    (mix10000, 128, 10000, 128)
+}
+
+mat_mul! {mat_mul_i64, i64,
+    (m016, 16, 16, 16)
+    (m064, 64, 64, 64)
+    (m127, 127, 127, 127)
+    (m256, 256, 256, 256)
 }
 
 #[bench]
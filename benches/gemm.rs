@@ -0,0 +1,65 @@
+#![feature(test)]
+#![allow(
+    clippy::many_single_char_names,
+    clippy::deref_addrof,
+    clippy::unreadable_literal,
+    clippy::many_single_char_names
+)]
+
+extern crate test;
+use test::{black_box, Bencher};
+
+use ndarray::prelude::*;
+use ndarray::LinalgScalar;
+
+// The per-element loop that is used for small products of non-BLAS element types,
+// and that was used for all sizes before the cache blocked kernel.
+fn naive_mat_mul<A: LinalgScalar>(a: &ArrayView2<'_, A>, b: &ArrayView2<'_, A>) -> Array2<A> {
+    let ((m, k), (_, n)) = (a.dim(), b.dim());
+    Array::from_shape_fn((m, n), |(i, j)| unsafe {
+        (0..k).fold(A::zero(), |s, x| s + *a.uget((i, x)) * *b.uget((x, j)))
+    })
+}
+
+macro_rules! gemm {
+    ($modname:ident, $ty:ident, $(($name:ident, $naive:ident, $n:expr))+) => {
+        mod $modname {
+            use super::*;
+            $(
+            #[bench]
+            fn $name(bench: &mut Bencher)
+            {
+                let a = Array::from_shape_fn(($n, $n), |(i, j)| ((i * 7 + j * 3) % 11) as $ty);
+                let b = Array::from_shape_fn(($n, $n), |(i, j)| ((i * 5 + j * 2) % 13) as $ty);
+                let a = black_box(a.view());
+                let b = black_box(b.view());
+                bench.iter(|| a.dot(&b));
+            }
+
+            #[bench]
+            fn $naive(bench: &mut Bencher)
+            {
+                let a = Array::from_shape_fn(($n, $n), |(i, j)| ((i * 7 + j * 3) % 11) as $ty);
+                let b = Array::from_shape_fn(($n, $n), |(i, j)| ((i * 5 + j * 2) % 13) as $ty);
+                let a = black_box(a.view());
+                let b = black_box(b.view());
+                bench.iter(|| naive_mat_mul(&a, &b));
+            }
+            )+
+        }
+    }
+}
+
+gemm! {gemm_i32, i32,
+    (m064, m064_naive, 64)
+    (m256, m256_naive, 256)
+    (m512, m512_naive, 512)
+}
+
+gemm! {gemm_i64, i64,
+    (m256, m256_naive, 256)
+}
+
+gemm! {gemm_i16, i16,
+    (m256, m256_naive, 256)
+}
@@ -0,0 +1,154 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The allocator interface used by owned arrays
+
+use alloc::alloc::{self as global, Layout};
+#[cfg(feature = "std")]
+use std::error::Error;
+use std::fmt;
+use std::ptr::{self, NonNull};
+
+use crate::private::PrivateMarker;
+
+/// A memory allocator for the storage of owned arrays.
+///
+/// The element storage of an [`Array`](type.Array.html) is allocated through the allocator
+/// type parameter of its representation, [`OwnedRepr<A, Al>`](struct.OwnedRepr.html).
+/// The default is [`Global`], the global allocator, and only arrays that use `Global`
+/// can be converted to and from `Vec` without copying.
+///
+/// Arrays with a custom allocator are created with the constructors that take the allocator
+/// as an argument, like [`from_elem_in`](struct.ArrayBase.html#method.from_elem_in),
+/// [`from_shape_vec_in`](struct.ArrayBase.html#method.from_shape_vec_in) and
+/// [`uninit_in`](struct.ArrayBase.html#method.uninit_in). Apart from the constructors
+/// and arithmetic operators that take an owned array as the left hand side operand,
+/// they support the same methods as other arrays. New arrays computed from them, for
+/// example with `.map()` or `&a + &b`, use the global allocator.
+///
+/// The allocator is never called with zero-sized layouts.
+///
+/// ```
+/// use std::alloc::{Layout, System};
+/// use std::ptr::NonNull;
+///
+/// use ndarray::{Array, Array2, ArrayAllocator, AllocError};
+///
+/// #[derive(Clone)]
+/// struct SystemAllocator;
+///
+/// unsafe impl ArrayAllocator for SystemAllocator {
+///     fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+///         use std::alloc::GlobalAlloc;
+///         NonNull::new(unsafe { System.alloc(layout) }).ok_or(AllocError)
+///     }
+///
+///     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+///         use std::alloc::GlobalAlloc;
+///         System.dealloc(ptr.as_ptr(), layout)
+///     }
+/// }
+///
+/// let a = Array::from_elem_in((3, 4), 0., SystemAllocator);
+/// let b = &a + 1.;
+/// assert_eq!(b, Array2::<f64>::from_elem((3, 4), 1.));
+/// ```
+///
+/// # Safety
+///
+/// Memory blocks returned from `allocate` and `grow` must be valid for reads and writes of
+/// `layout.size()` bytes, aligned to `layout.align()`, and stay valid until they are passed
+/// to `deallocate` or `grow` of the same allocator or a clone of it.
+pub unsafe trait ArrayAllocator {
+    /// Allocate a block of memory for `layout`.
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+
+    /// Deallocate the block of memory at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by this allocator (or a clone of it)
+    /// with the layout `layout`.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// Grow the block of memory at `ptr` to `new_layout`, keeping its contents.
+    ///
+    /// The default implementation allocates a new block, copies the contents and
+    /// deallocates the old block.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by this allocator (or a clone of it) with the layout
+    /// `old_layout`. `new_layout` must have the same alignment as `old_layout` and a
+    /// size that is greater than or equal to it.
+    unsafe fn grow(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout)
+        -> Result<NonNull<u8>, AllocError>
+    {
+        debug_assert!(new_layout.size() >= old_layout.size());
+        let new_ptr = self.allocate(new_layout)?;
+        ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), old_layout.size());
+        self.deallocate(ptr, old_layout);
+        Ok(new_ptr)
+    }
+
+    #[doc(hidden)]
+    // Whether this is the global allocator; memory from the global allocator can
+    // be exchanged with `Vec`.
+    fn __is_global(_: PrivateMarker) -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+}
+
+/// The global memory allocator.
+///
+/// This is the default allocator of owned arrays, and it allocates through the
+/// global allocator in the same way as `Vec`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Global;
+
+unsafe impl ArrayAllocator for Global {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        debug_assert_ne!(layout.size(), 0);
+        unsafe { NonNull::new(global::alloc(layout)).ok_or(AllocError) }
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        global::dealloc(ptr.as_ptr(), layout)
+    }
+
+    #[inline]
+    unsafe fn grow(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout)
+        -> Result<NonNull<u8>, AllocError>
+    {
+        debug_assert_eq!(old_layout.align(), new_layout.align());
+        NonNull::new(global::realloc(ptr.as_ptr(), old_layout, new_layout.size())).ok_or(AllocError)
+    }
+
+    #[doc(hidden)]
+    fn __is_global(_: PrivateMarker) -> bool {
+        true
+    }
+}
+
+/// An error returned by an [`ArrayAllocator`] when memory could not be allocated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AllocError {}
@@ -1,10 +1,13 @@
 use std::mem;
 use std::mem::ManuallyDrop;
-use std::ptr::NonNull;
+use std::ptr::{self, NonNull};
+use alloc::alloc::{handle_alloc_error, Layout};
 use alloc::slice;
 use alloc::borrow::ToOwned;
 use alloc::vec::Vec;
+use crate::allocator::{ArrayAllocator, Global};
 use crate::extension::nonnull;
+use crate::private::PrivateMarker;
 
 use rawpointer::PointerExt;
 
@@ -12,16 +15,25 @@ use rawpointer::PointerExt;
 ///
 /// *Don’t use this type directly—use the type alias
 /// [`Array`](type.Array.html) for the array type!*
+///
+/// The elements are allocated with the allocator `Al`, which is the global
+/// allocator by default; see [`ArrayAllocator`](trait.ArrayAllocator.html).
 // Like a Vec, but with non-unique ownership semantics
 //
 // repr(C) to make it transmutable OwnedRepr<A> -> OwnedRepr<B> if
 // transmutable A -> B.
+//
+// The allocation is compatible with `Vec<A>` when the allocator is the global allocator
+// and `align` is equal to the alignment of `A`; otherwise the allocation is managed
+// directly through the allocator, using the layout `capacity` × `A` with alignment `align`.
 #[derive(Debug)]
 #[repr(C)]
-pub struct OwnedRepr<A> {
+pub struct OwnedRepr<A, Al: ArrayAllocator = Global> {
     ptr: NonNull<A>,
     len: usize,
     capacity: usize,
+    align: usize,
+    alloc: Al,
 }
 
 impl<A> OwnedRepr<A> {
@@ -34,11 +46,142 @@ impl<A> OwnedRepr<A> {
             ptr,
             len,
             capacity,
+            align: mem::align_of::<A>(),
+            alloc: Global,
+        }
+    }
+
+    /// Create a new empty storage with room for `capacity` elements, where the
+    /// allocation is aligned to at least `align` bytes.
+    ///
+    /// **Panics** if `align` is not a power of two, or if the allocation size
+    /// overflows.
+    pub(crate) fn with_capacity_aligned(capacity: usize, align: usize) -> Self {
+        Self::with_capacity_aligned_in(capacity, align, Global)
+    }
+
+    /// Create a new storage from the elements of `iter`, aligned to at least
+    /// `align` bytes. `len` must be the exact length of the iterator.
+    pub(crate) fn from_iter_aligned<I>(iter: I, len: usize, align: usize) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        Self::from_iter_aligned_in(iter, len, align, Global)
+    }
+}
+
+impl<A, Al: ArrayAllocator> OwnedRepr<A, Al> {
+    /// Create a new empty storage with room for `capacity` elements, allocated with `alloc`,
+    /// where the allocation is aligned to at least `align` bytes.
+    ///
+    /// **Panics** if `align` is not a power of two, or if the allocation size
+    /// overflows.
+    pub(crate) fn with_capacity_aligned_in(capacity: usize, align: usize, alloc: Al) -> Self {
+        assert!(align.is_power_of_two(),
+                "ndarray: alignment must be a power of two, got {}", align);
+        let align = Ord::max(align, mem::align_of::<A>());
+        if Al::__is_global(PrivateMarker) && align == mem::align_of::<A>() {
+            return OwnedRepr::from(Vec::with_capacity(capacity)).move_to(alloc);
+        }
+        let capacity = if mem::size_of::<A>() == 0 { usize::MAX } else { capacity };
+        let layout = Self::layout_for(capacity, align);
+        let ptr = if layout.size() == 0 {
+            Self::dangling(align)
+        } else {
+            match alloc.allocate(layout) {
+                Ok(ptr) => ptr.cast::<A>(),
+                Err(_) => handle_alloc_error(layout),
+            }
+        };
+        OwnedRepr {
+            ptr,
+            len: 0,
+            capacity,
+            align,
+            alloc,
+        }
+    }
+
+    /// Create a new storage from the elements of `iter`, allocated with `alloc` and aligned
+    /// to at least `align` bytes. `len` must be the exact length of the iterator.
+    pub(crate) fn from_iter_aligned_in<I>(iter: I, len: usize, align: usize, alloc: Al) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let mut data = Self::with_capacity_aligned_in(len, align, alloc);
+        let mut iter = iter.into_iter();
+        while data.len < len {
+            match iter.next() {
+                Some(elt) => unsafe {
+                    // Length is updated after each write, so that a panic in the
+                    // iterator drops exactly the elements written so far.
+                    data.ptr.as_ptr().add(data.len).write(elt);
+                    data.len += 1;
+                }
+                None => break,
+            }
+        }
+        debug_assert_eq!(data.len, len, "Iterator was shorter than its reported length");
+        data
+    }
+
+    /// Move the elements to storage allocated by `alloc`, with the same alignment.
+    ///
+    /// If both allocators are the global allocator, the allocation is kept as it is.
+    pub(crate) fn move_to<Al2: ArrayAllocator>(self, alloc: Al2) -> OwnedRepr<A, Al2> {
+        let mut self_ = ManuallyDrop::new(self);
+        if Al::__is_global(PrivateMarker) && Al2::__is_global(PrivateMarker) {
+            // Both are the global allocator; the old allocator value needs no drop.
+            return OwnedRepr {
+                ptr: self_.ptr,
+                len: self_.len,
+                capacity: self_.capacity,
+                align: self_.align,
+                alloc,
+            };
+        }
+        let mut data = OwnedRepr::with_capacity_aligned_in(self_.len, self_.align, alloc);
+        unsafe {
+            ptr::copy_nonoverlapping(self_.ptr.as_ptr(), data.ptr.as_ptr(), self_.len);
+            data.len = self_.len;
+            self_.len = 0;
+            // drop the old storage, which now has no elements
+            ManuallyDrop::drop(&mut self_);
         }
+        data
     }
 
+    /// Return the alignment in bytes that the allocation is guaranteed to have
+    pub(crate) fn alignment(&self) -> usize { self.align }
+
+    /// Return the allocator of the storage
+    pub(crate) fn allocator(&self) -> &Al { &self.alloc }
+
     pub(crate) fn into_vec(self) -> Vec<A> {
-        ManuallyDrop::new(self).take_as_vec()
+        if self.is_vec_compatible() {
+            ManuallyDrop::new(self).take_as_vec()
+        } else {
+            // These allocations can't be given to Vec; move the elements out.
+            let mut self_ = ManuallyDrop::new(self);
+            let mut v = Vec::with_capacity(self_.len);
+            unsafe {
+                ptr::copy_nonoverlapping(self_.ptr.as_ptr(), v.as_mut_ptr(), self_.len);
+                v.set_len(self_.len);
+                self_.len = 0;
+                // drop the old storage, which now has no elements
+                ManuallyDrop::drop(&mut self_);
+            }
+            v
+        }
+    }
+
+    /// Decompose into pointer, length, capacity and allocator, without dropping
+    /// any elements or freeing the allocation.
+    pub(crate) fn into_raw_parts_with_alloc(self) -> (NonNull<A>, usize, usize, Al) {
+        let self_ = ManuallyDrop::new(self);
+        unsafe {
+            (self_.ptr, self_.len, self_.capacity, ptr::read(&self_.alloc))
+        }
     }
 
     pub(crate) fn as_slice(&self) -> &[A] {
@@ -69,19 +212,49 @@ impl<A> OwnedRepr<A> {
     }
 
     /// Reserve `additional` elements; return the new pointer
-    /// 
+    ///
     /// ## Safety
     ///
     /// Note that existing pointers into the data are invalidated
     #[must_use = "must use new pointer to update existing pointers"]
     pub(crate) fn reserve(&mut self, additional: usize) -> NonNull<A> {
-        self.modify_as_vec(|mut v| {
-            v.reserve(additional);
-            v
-        });
+        if self.is_vec_compatible() {
+            unsafe {
+                self.modify_as_vec(|mut v| {
+                    v.reserve(additional);
+                    v
+                });
+            }
+        } else {
+            self.reserve_in_allocator(additional);
+        }
         self.as_nonnull_mut()
     }
 
+    /// Reserve using the allocator directly; keeps the alignment of the allocation.
+    fn reserve_in_allocator(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("ndarray: capacity overflow");
+        if required <= self.capacity {
+            return;
+        }
+        // amortized growth, like Vec
+        let new_capacity = Ord::max(required, self.capacity.saturating_mul(2));
+        let new_layout = Self::layout_for(new_capacity, self.align);
+        let old_layout = Self::layout_for(self.capacity, self.align);
+        let result = unsafe {
+            if old_layout.size() == 0 {
+                self.alloc.allocate(new_layout)
+            } else {
+                self.alloc.grow(self.ptr.cast::<u8>(), old_layout, new_layout)
+            }
+        };
+        match result {
+            Ok(ptr) => self.ptr = ptr.cast::<A>(),
+            Err(_) => handle_alloc_error(new_layout),
+        }
+        self.capacity = new_capacity;
+    }
+
     /// Set the valid length of the data
     ///
     /// ## Safety
@@ -105,7 +278,7 @@ impl<A> OwnedRepr<A> {
     ///
     /// Caller must ensure the two types have the same representation.
     /// **Panics** if sizes don't match (which is not a sufficient check).
-    pub(crate) unsafe fn data_subst<B>(self) -> OwnedRepr<B> {
+    pub(crate) unsafe fn data_subst<B>(self) -> OwnedRepr<B, Al> {
         // necessary but not sufficient check
         assert_eq!(mem::size_of::<A>(), mem::size_of::<B>());
         let self_ = ManuallyDrop::new(self);
@@ -113,15 +286,58 @@ impl<A> OwnedRepr<A> {
             ptr: self_.ptr.cast::<B>(),
             len: self_.len,
             capacity: self_.capacity,
+            align: self_.align,
+            alloc: ptr::read(&self_.alloc),
         }
     }
 
-    fn modify_as_vec(&mut self, f: impl FnOnce(Vec<A>) -> Vec<A>) {
+    /// Whether the allocation can be converted to and from `Vec<A>`
+    fn is_vec_compatible(&self) -> bool {
+        Al::__is_global(PrivateMarker) && self.align == mem::align_of::<A>()
+    }
+
+    fn layout_for(capacity: usize, align: usize) -> Layout {
+        mem::size_of::<A>().checked_mul(capacity)
+            .and_then(|size| Layout::from_size_align(size, align).ok())
+            .expect("ndarray: capacity overflow")
+    }
+
+    /// Return a dangling, non-null pointer with the given alignment
+    fn dangling(align: usize) -> NonNull<A> {
+        unsafe { NonNull::new_unchecked(align as *mut A) }
+    }
+
+    /// Free the allocation without dropping any elements.
+    ///
+    /// ## Safety
+    ///
+    /// Must only be called once, and only for storage that is not Vec compatible.
+    unsafe fn deallocate(&mut self) {
+        debug_assert!(!self.is_vec_compatible());
+        let layout = Self::layout_for(self.capacity, self.align);
+        if layout.size() != 0 {
+            self.alloc.deallocate(self.ptr.cast::<u8>(), layout);
+        }
+        self.len = 0;
+        self.capacity = 0;
+    }
+
+    /// ## Safety
+    ///
+    /// Only for storage that is Vec compatible.
+    unsafe fn modify_as_vec(&mut self, f: impl FnOnce(Vec<A>) -> Vec<A>) {
+        debug_assert!(self.is_vec_compatible());
         let v = self.take_as_vec();
-        *self = Self::from(f(v));
+        let new = OwnedRepr::from(f(v));
+        // the allocator is the global allocator; the fields are replaced without drop
+        self.ptr = new.ptr;
+        self.len = new.len;
+        self.capacity = new.capacity;
+        mem::forget(new);
     }
 
     fn take_as_vec(&mut self) -> Vec<A> {
+        debug_assert!(self.is_vec_compatible());
         let capacity = self.capacity;
         let len = self.len;
         self.len = 0;
@@ -132,30 +348,61 @@ impl<A> OwnedRepr<A> {
     }
 }
 
-impl<A> Clone for OwnedRepr<A>
-    where A: Clone
+impl<A, Al> Clone for OwnedRepr<A, Al>
+    where A: Clone,
+          Al: ArrayAllocator + Clone,
 {
     fn clone(&self) -> Self {
-        Self::from(self.as_slice().to_owned())
+        if self.is_vec_compatible() {
+            OwnedRepr::from(self.as_slice().to_owned()).move_to(self.alloc.clone())
+        } else {
+            Self::from_iter_aligned_in(self.as_slice().iter().cloned(), self.len, self.align,
+                                       self.alloc.clone())
+        }
     }
 
     fn clone_from(&mut self, other: &Self) {
-        let mut v = self.take_as_vec();
+        if !self.is_vec_compatible() || !other.is_vec_compatible() {
+            *self = other.clone();
+            return;
+        }
         let other = other.as_slice();
-
-        if v.len() > other.len() {
-            v.truncate(other.len());
+        unsafe {
+            self.modify_as_vec(|mut v| {
+                if v.len() > other.len() {
+                    v.truncate(other.len());
+                }
+                let (front, back) = other.split_at(v.len());
+                v.clone_from_slice(front);
+                v.extend_from_slice(back);
+                v
+            });
         }
-        let (front, back) = other.split_at(v.len());
-        v.clone_from_slice(front);
-        v.extend_from_slice(back);
-        *self = Self::from(v);
     }
 }
 
-impl<A> Drop for OwnedRepr<A> {
+impl<A, Al: ArrayAllocator> Drop for OwnedRepr<A, Al> {
     fn drop(&mut self) {
-        if self.capacity > 0 {
+        if !self.is_vec_compatible() {
+            // Deallocate even if dropping an element panics
+            struct DeallocOnDrop<'a, A, Al: ArrayAllocator>(&'a mut OwnedRepr<A, Al>);
+
+            impl<A, Al: ArrayAllocator> Drop for DeallocOnDrop<'_, A, Al> {
+                fn drop(&mut self) {
+                    unsafe {
+                        self.0.deallocate();
+                    }
+                }
+            }
+
+            let guard = DeallocOnDrop(self);
+            if mem::needs_drop::<A>() {
+                unsafe {
+                    let elements = slice::from_raw_parts_mut(guard.0.ptr.as_ptr(), guard.0.len);
+                    ptr::drop_in_place(elements);
+                }
+            }
+        } else if self.capacity > 0 {
             // correct because: If the elements don't need dropping, an
             // empty Vec is ok. Only the Vec's allocation needs dropping.
             //
@@ -174,6 +421,5 @@ impl<A> Drop for OwnedRepr<A> {
     }
 }
 
-unsafe impl<A> Sync for OwnedRepr<A> where A: Sync { }
-unsafe impl<A> Send for OwnedRepr<A> where A: Send { }
-
+unsafe impl<A, Al> Sync for OwnedRepr<A, Al> where A: Sync, Al: ArrayAllocator + Sync { }
+unsafe impl<A, Al> Send for OwnedRepr<A, Al> where A: Send, Al: ArrayAllocator + Send { }
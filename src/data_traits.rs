@@ -17,6 +17,8 @@ use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use crate::{ArrayBase, CowRepr, Dimension, OwnedArcRepr, OwnedRepr, RawViewRepr, ViewRepr};
+use crate::allocator::{ArrayAllocator, Global};
+use crate::private::PrivateMarker;
 
 /// Array representation trait.
 ///
@@ -39,6 +41,13 @@ pub unsafe trait RawData: Sized {
     #[doc(hidden)]
     fn _is_pointer_inbounds(&self, ptr: *const Self::Elem) -> bool;
 
+    #[doc(hidden)]
+    // The alignment guaranteed for the allocation of owned storage
+    #[inline]
+    fn _storage_alignment(&self) -> usize {
+        mem::align_of::<Self::Elem>()
+    }
+
     private_decl! {}
 }
 
@@ -216,6 +225,10 @@ unsafe impl<A> RawData for OwnedArcRepr<A> {
         self.0._is_pointer_inbounds(self_ptr)
     }
 
+    fn _storage_alignment(&self) -> usize {
+        self.0.alignment()
+    }
+
     private_impl! {}
 }
 
@@ -232,9 +245,9 @@ where
         if Arc::get_mut(&mut self_.data.0).is_some() {
             return;
         }
-        if self_.dim.size() <= self_.data.0.len() / 2 {
-            // Clone only the visible elements if the current view is less than
-            // half of backing data.
+        if self_.dim.size() < self_.data.0.len() {
+            // Clone only the visible elements if the current view doesn't cover
+            // all of the backing data.
             *self_ = self_.to_owned().into_shared();
             return;
         }
@@ -291,7 +304,7 @@ unsafe impl<A> RawDataClone for OwnedArcRepr<A> {
     }
 }
 
-unsafe impl<A> RawData for OwnedRepr<A> {
+unsafe impl<A, Al: ArrayAllocator> RawData for OwnedRepr<A, Al> {
     type Elem = A;
 
     fn _data_slice(&self) -> Option<&[A]> {
@@ -305,10 +318,14 @@ unsafe impl<A> RawData for OwnedRepr<A> {
         self_ptr >= ptr && self_ptr <= end
     }
 
+    fn _storage_alignment(&self) -> usize {
+        self.alignment()
+    }
+
     private_impl! {}
 }
 
-unsafe impl<A> RawDataMut for OwnedRepr<A> {
+unsafe impl<A, Al: ArrayAllocator> RawDataMut for OwnedRepr<A, Al> {
     #[inline]
     fn try_ensure_unique<D>(_: &mut ArrayBase<Self, D>)
     where
@@ -323,22 +340,32 @@ unsafe impl<A> RawDataMut for OwnedRepr<A> {
     }
 }
 
-unsafe impl<A> Data for OwnedRepr<A> {
+unsafe impl<A, Al: ArrayAllocator> Data for OwnedRepr<A, Al> {
     #[inline]
     fn into_owned<D>(self_: ArrayBase<Self, D>) -> ArrayBase<OwnedRepr<Self::Elem>, D>
     where
         A: Clone,
         D: Dimension,
     {
-        self_
+        if Al::__is_global(PrivateMarker) {
+            // the storage is moved as it is
+            let ArrayBase { data, ptr, dim, strides } = self_;
+            unsafe {
+                ArrayBase::from_data_ptr(data.move_to(Global), ptr)
+                    .with_strides_dim(strides, dim)
+            }
+        } else {
+            self_.to_owned()
+        }
     }
 }
 
-unsafe impl<A> DataMut for OwnedRepr<A> {}
+unsafe impl<A, Al: ArrayAllocator> DataMut for OwnedRepr<A, Al> {}
 
-unsafe impl<A> RawDataClone for OwnedRepr<A>
+unsafe impl<A, Al> RawDataClone for OwnedRepr<A, Al>
 where
     A: Clone,
+    Al: ArrayAllocator + Clone,
 {
     unsafe fn clone_with_ptr(&self, ptr: NonNull<Self::Elem>) -> (Self, NonNull<Self::Elem>) {
         let mut u = self.clone();
@@ -456,6 +483,9 @@ pub unsafe trait DataOwned: Data {
     #[doc(hidden)]
     fn new(elements: Vec<Self::Elem>) -> Self;
 
+    #[doc(hidden)]
+    fn from_owned_repr(data: OwnedRepr<Self::Elem>) -> Self;
+
     /// Converts the data representation to a shared (copy on write)
     /// representation, without any copying.
     #[doc(hidden)]
@@ -472,6 +502,8 @@ pub unsafe trait DataShared: Clone + Data + RawDataClone {}
 unsafe impl<A> DataShared for OwnedArcRepr<A> {}
 unsafe impl<'a, A> DataShared for ViewRepr<&'a A> {}
 
+// Only for the global allocator, so that the allocator of `Array::zeros(..)` and similar
+// constructors can always be inferred.
 unsafe impl<A> DataOwned for OwnedRepr<A> {
     type MaybeUninit = OwnedRepr<MaybeUninit<A>>;
 
@@ -479,6 +511,10 @@ unsafe impl<A> DataOwned for OwnedRepr<A> {
         OwnedRepr::from(elements)
     }
 
+    fn from_owned_repr(data: OwnedRepr<A>) -> Self {
+        data
+    }
+
     fn into_shared(self) -> OwnedArcRepr<A> {
         OwnedArcRepr(Arc::new(self))
     }
@@ -491,6 +527,10 @@ unsafe impl<A> DataOwned for OwnedArcRepr<A> {
         OwnedArcRepr(Arc::new(OwnedRepr::from(elements)))
     }
 
+    fn from_owned_repr(data: OwnedRepr<A>) -> Self {
+        OwnedArcRepr(Arc::new(data))
+    }
+
     fn into_shared(self) -> OwnedArcRepr<A> {
         self
     }
@@ -515,6 +555,14 @@ unsafe impl<'a, A> RawData for CowRepr<'a, A> {
         }
     }
 
+    #[inline]
+    fn _storage_alignment(&self) -> usize {
+        match self {
+            CowRepr::View(view) => view._storage_alignment(),
+            CowRepr::Owned(data) => data._storage_alignment(),
+        }
+    }
+
     private_impl! {}
 }
 
@@ -625,8 +673,8 @@ pub trait RawDataSubst<A>: RawData {
     unsafe fn data_subst(self) -> Self::Output;
 }
 
-impl<A, B> RawDataSubst<B> for OwnedRepr<A> {
-    type Output = OwnedRepr<B>;
+impl<A, B, Al: ArrayAllocator> RawDataSubst<B> for OwnedRepr<A, Al> {
+    type Output = OwnedRepr<B, Al>;
 
     unsafe fn data_subst(self) -> Self::Output {
         self.data_subst()
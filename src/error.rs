@@ -5,16 +5,53 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
-use super::Dimension;
+use super::{Axis, Dimension};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::error::Error;
 use std::fmt;
 
 /// An error related to array shape or layout.
+///
+/// Apart from its [`ErrorKind`], the error can carry context about where it happened:
+/// the name of the operation, the expected and actual shapes, the axis, and the
+/// index of the offending input. The context is shown when the error is printed.
+///
+/// Errors are compared by kind only; the context is ignored by `==`.
+///
+/// ```
+/// use ndarray::{concatenate, Array2, Axis, ErrorKind};
+///
+/// let a = Array2::<f64>::zeros((2, 3));
+/// let b = Array2::<f64>::zeros((2, 4));
+/// let err = concatenate(Axis(0), &[a.view(), b.view()]).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+/// assert_eq!(err.operation(), Some("concatenate"));
+/// assert_eq!(err.expected_shape(), Some(&[2, 3][..]));
+/// assert_eq!(err.actual_shape(), Some(&[2, 4][..]));
+/// assert_eq!(err.axis(), Some(Axis(0)));
+/// assert_eq!(err.index(), Some(1));
+/// assert_eq!(
+///     err.to_string(),
+///     "ShapeError/IncompatibleShape: incompatible shapes (operation: concatenate, \
+///      expected shape: [2, 3], actual shape: [2, 4], axis: 0, index: 1)");
+/// ```
 #[derive(Clone)]
 pub struct ShapeError {
     // we want to be able to change this representation later
     repr: ErrorKind,
+    // boxed to keep results with a shape error small
+    context: Option<Box<ErrorContext>>,
+}
+
+#[derive(Clone, Default)]
+struct ErrorContext {
+    operation: Option<&'static str>,
+    expected: Option<Vec<usize>>,
+    actual: Option<Vec<usize>>,
+    axis: Option<usize>,
+    index: Option<usize>,
 }
 
 impl ShapeError {
@@ -28,6 +65,61 @@ impl ShapeError {
     pub fn from_kind(error: ErrorKind) -> Self {
         from_kind(error)
     }
+
+    /// Return the name of the operation that failed, if known.
+    pub fn operation(&self) -> Option<&'static str> {
+        self.context.as_ref().and_then(|c| c.operation)
+    }
+
+    /// Return the shape that the operation expected, if known.
+    pub fn expected_shape(&self) -> Option<&[usize]> {
+        self.context.as_ref().and_then(|c| c.expected.as_deref())
+    }
+
+    /// Return the shape that the operation got instead, if known.
+    pub fn actual_shape(&self) -> Option<&[usize]> {
+        self.context.as_ref().and_then(|c| c.actual.as_deref())
+    }
+
+    /// Return the axis the error relates to, if known.
+    pub fn axis(&self) -> Option<Axis> {
+        self.context.as_ref().and_then(|c| c.axis).map(Axis)
+    }
+
+    /// Return the index of the offending input or element, if known.
+    pub fn index(&self) -> Option<usize> {
+        self.context.as_ref().and_then(|c| c.index)
+    }
+
+    /// Set the name of the operation that failed.
+    pub fn with_operation(mut self, operation: &'static str) -> Self {
+        self.context_mut().operation = Some(operation);
+        self
+    }
+
+    /// Set the expected and the actual shape.
+    pub fn with_shapes(mut self, expected: &[usize], actual: &[usize]) -> Self {
+        let context = self.context_mut();
+        context.expected = Some(expected.to_vec());
+        context.actual = Some(actual.to_vec());
+        self
+    }
+
+    /// Set the axis the error relates to.
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.context_mut().axis = Some(axis.index());
+        self
+    }
+
+    /// Set the index of the offending input or element.
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.context_mut().index = Some(index);
+        self
+    }
+
+    fn context_mut(&mut self) -> &mut ErrorContext {
+        self.context.get_or_insert_with(Default::default)
+    }
 }
 
 /// Error code for an error related to array shape or layout.
@@ -53,7 +145,7 @@ pub enum ErrorKind {
 
 #[inline(always)]
 pub fn from_kind(k: ErrorKind) -> ShapeError {
-    ShapeError { repr: k }
+    ShapeError { repr: k, context: None }
 }
 
 impl PartialEq for ErrorKind {
@@ -83,7 +175,34 @@ impl fmt::Display for ShapeError {
             ErrorKind::Unsupported => "unsupported operation",
             ErrorKind::Overflow => "arithmetic overflow",
         };
-        write!(f, "ShapeError/{:?}: {}", self.kind(), description)
+        write!(f, "ShapeError/{:?}: {}", self.kind(), description)?;
+        if let Some(context) = &self.context {
+            let mut sep = " (";
+            let mut field = |f: &mut fmt::Formatter<'_>, args: fmt::Arguments<'_>| {
+                let res = write!(f, "{}{}", sep, args);
+                sep = ", ";
+                res
+            };
+            if let Some(operation) = context.operation {
+                field(f, format_args!("operation: {}", operation))?;
+            }
+            if let Some(expected) = &context.expected {
+                field(f, format_args!("expected shape: {:?}", expected))?;
+            }
+            if let Some(actual) = &context.actual {
+                field(f, format_args!("actual shape: {:?}", actual))?;
+            }
+            if let Some(axis) = context.axis {
+                field(f, format_args!("axis: {}", axis))?;
+            }
+            if let Some(index) = context.index {
+                field(f, format_args!("index: {}", index))?;
+            }
+            if sep != " (" {
+                f.write_str(")")?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -93,10 +212,11 @@ impl fmt::Debug for ShapeError {
     }
 }
 
-pub fn incompatible_shapes<D, E>(_a: &D, _b: &E) -> ShapeError
+/// Incompatible shapes error, with `expected` and `actual` as context
+pub fn incompatible_shapes<D, E>(expected: &D, actual: &E) -> ShapeError
 where
     D: Dimension,
     E: Dimension,
 {
-    from_kind(ErrorKind::IncompatibleShape)
+    from_kind(ErrorKind::IncompatibleShape).with_shapes(expected.slice(), actual.slice())
 }
@@ -0,0 +1,53 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::slice::SliceArg;
+
+/// Methods specific to `ArcArray`.
+///
+/// ***See also all methods for [`ArrayBase`]***
+///
+/// [`ArrayBase`]: struct.ArrayBase.html
+impl<A, D> ArcArray<A, D>
+where
+    D: Dimension,
+{
+    /// Return a sliced array that shares the storage of `self`, without copying any
+    /// elements.
+    ///
+    /// The result is an owned `ArcArray` of its own and it can be sent to other threads
+    /// or outlive `self`. The shared storage is kept alive as long as any array uses it.
+    /// When one of the arrays that share storage is mutated, only the elements visible
+    /// in it are copied to new storage (copy on write).
+    ///
+    /// See [*Slicing*](#slicing) for full documentation.
+    /// See also [`s!`], [`SliceArg`], and [`SliceInfo`](crate::SliceInfo).
+    ///
+    /// **Panics** if an index is out of bounds or step size is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `info` does not match the number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{ArcArray, s};
+    ///
+    /// let a = ArcArray::from_shape_fn((4, 4), |(i, j)| i * 4 + j);
+    /// let mut tile = a.slice_shared(s![2.., 2..]);
+    /// assert_eq!(tile.as_ptr(), &a[[2, 2]] as *const _);
+    ///
+    /// // mutation copies the tile only
+    /// tile.fill(0);
+    /// assert_eq!(a[[2, 2]], 10);
+    /// assert_eq!(tile.into_owned().into_raw_vec(), vec![0; 4]);
+    /// ```
+    pub fn slice_shared<I>(&self, info: I) -> ArcArray<A, I::OutDim>
+    where
+        I: SliceArg<D>,
+    {
+        self.clone().slice_move(info)
+    }
+}
@@ -30,6 +30,7 @@ use crate::indices;
 use crate::iterators::to_vec;
 use crate::iterators::to_vec_mapped;
 use crate::iterators::TrustedIterator;
+use crate::{ArrayAllocator, OwnedRepr};
 use crate::StrideShape;
 #[cfg(feature = "std")]
 use crate::{geomspace, linspace, logspace};
@@ -322,6 +323,51 @@ where
         Self::from_elem(shape, A::zero())
     }
 
+    /// Create an array with copies of `elem`, shape `shape`, where the array's
+    /// memory allocation is aligned to at least `align` bytes.
+    ///
+    /// An array in standard or column major layout then has its first element at
+    /// an address that is a multiple of `align`, which is what SIMD kernels or
+    /// foreign libraries often require of `.as_ptr()`. The alignment is kept
+    /// when the array is cloned, converted with `.to_owned()` or appended to.
+    ///
+    /// **Panics** if `align` is not a power of two, or if the product of non-zero
+    /// axis lengths overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_elem_aligned((3, 5), 1.5_f32, 64);
+    ///
+    /// assert_eq!(a.alignment(), 64);
+    /// assert_eq!(a.as_ptr() as usize % 64, 0);
+    /// ```
+    pub fn from_elem_aligned<Sh>(shape: Sh, elem: A, align: usize) -> Self
+    where
+        A: Clone,
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        let shape = shape.into_shape();
+        let size = size_of_shape_checked_unwrap!(&shape.dim);
+        let data = OwnedRepr::from_iter_aligned((0..size).map(|_| elem.clone()), size, align);
+        unsafe { Self::from_owned_repr_unchecked(shape, data) }
+    }
+
+    /// Create an array with zeros, shape `shape`, where the array's memory allocation is
+    /// aligned to at least `align` bytes.
+    ///
+    /// See [`from_elem_aligned`](ArrayBase::from_elem_aligned) for more information.
+    ///
+    /// **Panics** if `align` is not a power of two, or if the product of non-zero
+    /// axis lengths overflows `isize`.
+    pub fn zeros_aligned<Sh>(shape: Sh, align: usize) -> Self
+    where
+        A: Clone + Zero,
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        Self::from_elem_aligned(shape, A::zero(), align)
+    }
+
     /// Create an array with ones, shape `shape`.
     ///
     /// **Panics** if the product of non-zero axis lengths overflows `isize`.
@@ -446,9 +492,13 @@ where
     fn from_shape_vec_impl(shape: StrideShape<D>, v: Vec<A>) -> Result<Self, ShapeError> {
         let dim = shape.dim;
         let is_custom = shape.strides.is_custom();
-        dimension::can_index_slice_with_strides(&v, &dim, &shape.strides)?;
+        dimension::can_index_slice_with_strides(&v, &dim, &shape.strides)
+            .map_err(|e| {
+                e.with_operation("from_shape_vec").with_shapes(dim.slice(), &[v.len()])
+            })?;
         if !is_custom && dim.size() != v.len() {
-            return Err(error::incompatible_shapes(&Ix1(v.len()), &dim));
+            return Err(error::incompatible_shapes(&dim, &Ix1(v.len()))
+                .with_operation("from_shape_vec"));
         }
         let strides = shape.strides.strides_for_dim(&dim);
         unsafe { Ok(Self::from_vec_dim_stride_unchecked(dim, strides, v)) }
@@ -496,6 +546,25 @@ where
         ArrayBase::from_data_ptr(DataOwned::new(v), ptr).with_strides_dim(strides, dim)
     }
 
+    /// Creates an array from owned storage and interpret it according to the
+    /// provided shape and strides.
+    ///
+    /// # Safety
+    ///
+    /// See from_shape_vec_unchecked
+    pub(crate) unsafe fn from_owned_repr_unchecked<Sh>(shape: Sh, mut data: OwnedRepr<A>) -> Self
+    where
+        Sh: Into<StrideShape<D>>,
+    {
+        let shape = shape.into();
+        let dim = shape.dim;
+        let strides = shape.strides.strides_for_dim(&dim);
+        debug_assert!(dimension::can_index_slice(data.as_slice(), &dim, &strides).is_ok());
+
+        let ptr = data.as_nonnull_mut().add(offset_from_low_addr_ptr_to_logical_ptr(&dim, &strides));
+        ArrayBase::from_data_ptr(DataOwned::from_owned_repr(data), ptr).with_strides_dim(strides, dim)
+    }
+
     /// Creates an array from an iterator, mapped by `map` and interpret it according to the
     /// provided shape and strides.
     ///
@@ -575,6 +644,28 @@ where
         }
     }
 
+    /// Create an array with uninitalized elements, shape `shape`, where the array's
+    /// memory allocation is aligned to at least `align` bytes.
+    ///
+    /// See [`uninit`](ArrayBase::uninit) for how to initialize the array and
+    /// [`from_elem_aligned`](ArrayBase::from_elem_aligned) for more information about
+    /// alignment.
+    ///
+    /// **Panics** if `align` is not a power of two, or if the number of elements in `shape`
+    /// would overflow isize.
+    pub fn uninit_aligned<Sh>(shape: Sh, align: usize) -> ArrayBase<S::MaybeUninit, D>
+    where
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        unsafe {
+            let shape = shape.into_shape();
+            let size = size_of_shape_checked_unwrap!(&shape.dim);
+            let mut data = OwnedRepr::with_capacity_aligned(size, align);
+            data.set_len(size);
+            ArrayBase::from_owned_repr_unchecked(shape, data)
+        }
+    }
+
     /// Create an array with uninitalized elements, shape `shape`.
     ///
     /// The uninitialized elements of type `A` are represented by the type `MaybeUninit<A>`,
@@ -648,6 +739,107 @@ where
 
 }
 
+/// ## Constructor methods for arrays with a custom allocator
+///
+/// These constructors take the allocator to use for the array's storage as an argument,
+/// which is necessary for allocators that can't be created with `Default`, for example
+/// a handle to an arena. See [`ArrayAllocator`](trait.ArrayAllocator.html).
+impl<A, D, Al> ArrayBase<OwnedRepr<A, Al>, D>
+where
+    D: Dimension,
+    Al: ArrayAllocator,
+{
+    /// Create an array with copies of `elem`, shape `shape`, allocated with `alloc`.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::{Array, Global};
+    ///
+    /// let a = Array::from_elem_in((2, 3), 1.5, Global);
+    /// assert_eq!(a, Array::from_elem((2, 3), 1.5));
+    /// ```
+    pub fn from_elem_in<Sh>(shape: Sh, elem: A, alloc: Al) -> Self
+    where
+        A: Clone,
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        let shape = shape.into_shape();
+        let size = size_of_shape_checked_unwrap!(&shape.dim);
+        let data = OwnedRepr::from_iter_aligned_in((0..size).map(|_| elem.clone()), size,
+                                                   mem::align_of::<A>(), alloc);
+        unsafe { Self::from_repr_unchecked(shape, data) }
+    }
+
+    /// Create an array with the given shape from a vector, where the elements are moved
+    /// to storage allocated with `alloc`.
+    ///
+    /// If `Al` is the global allocator, the vector's allocation is used as it is.
+    ///
+    /// **Errors** in the same cases as [`from_shape_vec`](ArrayBase::from_shape_vec).
+    pub fn from_shape_vec_in<Sh>(shape: Sh, v: Vec<A>, alloc: Al) -> Result<Self, ShapeError>
+    where
+        Sh: Into<StrideShape<D>>,
+    {
+        let shape = shape.into();
+        let dim = &shape.dim;
+        dimension::can_index_slice_with_strides(&v, dim, &shape.strides)
+            .map_err(|e| {
+                e.with_operation("from_shape_vec_in").with_shapes(dim.slice(), &[v.len()])
+            })?;
+        if !shape.strides.is_custom() && dim.size() != v.len() {
+            return Err(error::incompatible_shapes(dim, &Ix1(v.len()))
+                .with_operation("from_shape_vec_in"));
+        }
+        unsafe { Ok(Self::from_repr_unchecked(shape, OwnedRepr::from(v).move_to(alloc))) }
+    }
+
+    /// Create an array with uninitalized elements, shape `shape`, allocated with `alloc`.
+    ///
+    /// See [`uninit`](ArrayBase::uninit) for how to initialize the array.
+    ///
+    /// **Panics** if the number of elements in `shape` would overflow isize.
+    pub fn uninit_in<Sh>(shape: Sh, alloc: Al) -> ArrayBase<OwnedRepr<MaybeUninit<A>, Al>, D>
+    where
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        ArrayBase::uninit_aligned_in(shape, mem::align_of::<A>(), alloc)
+    }
+
+    pub(crate) fn uninit_aligned_in<Sh>(shape: Sh, align: usize, alloc: Al)
+        -> ArrayBase<OwnedRepr<MaybeUninit<A>, Al>, D>
+    where
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        unsafe {
+            let shape = shape.into_shape();
+            let size = size_of_shape_checked_unwrap!(&shape.dim);
+            let mut data = OwnedRepr::with_capacity_aligned_in(size, align, alloc);
+            data.set_len(size);
+            ArrayBase::from_repr_unchecked(shape, data)
+        }
+    }
+
+    /// Creates an array from owned storage and interpret it according to the
+    /// provided shape and strides.
+    ///
+    /// # Safety
+    ///
+    /// See from_shape_vec_unchecked
+    pub(crate) unsafe fn from_repr_unchecked<Sh>(shape: Sh, mut data: OwnedRepr<A, Al>) -> Self
+    where
+        Sh: Into<StrideShape<D>>,
+    {
+        let shape = shape.into();
+        let dim = shape.dim;
+        let strides = shape.strides.strides_for_dim(&dim);
+        debug_assert!(dimension::can_index_slice(data.as_slice(), &dim, &strides).is_ok());
+
+        let ptr = data.as_nonnull_mut().add(offset_from_low_addr_ptr_to_logical_ptr(&dim, &strides));
+        ArrayBase::from_data_ptr(data, ptr).with_strides_dim(strides, dim)
+    }
+}
+
 impl<S, A, D> ArrayBase<S, D>
 where
     S: DataOwned<Elem = MaybeUninit<A>>,
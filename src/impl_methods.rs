@@ -6,7 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::mem::{size_of, ManuallyDrop};
+use std::mem::{align_of, size_of, ManuallyDrop};
 use alloc::slice;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -28,13 +28,14 @@ use crate::error::{self, ErrorKind, ShapeError, from_kind};
 use crate::math_cell::MathCell;
 use crate::itertools::zip;
 use crate::AxisDescription;
+use crate::OwnedRepr;
 use crate::order::Order;
 use crate::shape_builder::ShapeArg;
 use crate::zip::{IntoNdProducer, Zip};
 
 use crate::iter::{
-    AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut, ExactChunks, ExactChunksMut,
-    IndexedIter, IndexedIterMut, Iter, IterMut, Lanes, LanesMut, Windows,
+    AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut, Chunks, ChunksMut, ExactChunks,
+    ExactChunksMut, IndexedIter, IndexedIterMut, Iter, IterMut, Lanes, LanesMut, Windows,
 };
 use crate::slice::{MultiSliceArg, SliceArg};
 use crate::stacking::concatenate;
@@ -209,6 +210,11 @@ where
         A: Clone,
         S: Data,
     {
+        // keep the alignment of arrays with over-aligned storage
+        let align = self.data._storage_alignment();
+        if align > align_of::<A>() {
+            return self.to_owned_aligned(align);
+        }
         if let Some(slc) = self.as_slice_memory_order() {
             unsafe {
                 Array::from_shape_vec_unchecked(
@@ -221,6 +227,64 @@ where
         }
     }
 
+    /// Return an uniquely owned copy of the array, where the array's memory
+    /// allocation is aligned to at least `align` bytes.
+    ///
+    /// If the input array is contiguous, then the output array will have the same
+    /// memory layout. Otherwise, the output array is in standard layout.
+    ///
+    /// **Panics** if `align` is not a power of two.
+    ///
+    /// ```
+    /// use ndarray::{array, s};
+    ///
+    /// let a = array![[1., 2., 3.], [4., 5., 6.]];
+    /// let b = a.slice(s![.., 1..]).to_owned_aligned(32);
+    ///
+    /// assert_eq!(b, array![[2., 3.], [5., 6.]]);
+    /// assert_eq!(b.alignment(), 32);
+    /// assert_eq!(b.as_ptr() as usize % 32, 0);
+    /// ```
+    pub fn to_owned_aligned(&self, align: usize) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            let data = OwnedRepr::from_iter_aligned(slc.iter().cloned(), slc.len(), align);
+            unsafe {
+                Array::from_owned_repr_unchecked(
+                    self.dim.clone().strides(self.strides.clone()),
+                    data,
+                )
+            }
+        } else {
+            let mut array = Array::uninit_aligned(self.raw_dim(), align);
+            self.assign_to(&mut array);
+            unsafe {
+                array.assume_init()
+            }
+        }
+    }
+
+    /// Return the alignment in bytes that the memory allocation of the array
+    /// is guaranteed to have.
+    ///
+    /// This is the alignment of the element type, unless the array was created
+    /// with a constructor like [`zeros_aligned`](ArrayBase::zeros_aligned).
+    /// Note that the first element of the array is only at an aligned address if
+    /// it is the first element of the allocation, as it is for arrays that were
+    /// created in standard or column major layout and not sliced since.
+    ///
+    /// Array views don't own their storage, and for them this is always the alignment
+    /// of the element type.
+    pub fn alignment(&self) -> usize
+    where
+        S: Data,
+    {
+        self.data._storage_alignment()
+    }
+
     /// Return a shared ownership (copy on write) array, cloning the array
     /// elements if necessary.
     pub fn to_shared(&self) -> ArcArray<A, D>
@@ -1355,6 +1419,79 @@ where
         ExactChunksMut::new(self.view_mut(), chunk_size)
     }
 
+    /// Return a chunks producer (and iterable).
+    ///
+    /// It produces the chunks of a given n-dimensional chunk size, covering the
+    /// whole array: where the array's length along an axis is not a multiple of the
+    /// chunk size, the last chunks along that axis are smaller.
+    ///
+    /// The produced element is a `ArrayView<A, D>` with the dimension `chunk_size`,
+    /// or smaller at the edges of the array.
+    ///
+    /// **Panics** if any dimension of `chunk_size` is zero<br>
+    /// (**Panics** if `D` is `IxDyn` and `chunk_size` does not match the
+    /// number of array axes.)
+    ///
+    /// ```rust
+    /// use ndarray::{Array, Zip};
+    /// use ndarray::arr2;
+    /// let a = Array::from_shape_fn((5, 7), |(i, j)| i * 7 + j);
+    ///
+    /// // The sum of each 2 × 3 tile, including the partial tiles at the edges
+    /// let sums = Zip::from(a.chunks((2, 3))).map_collect(|tile| tile.sum());
+    /// assert_eq!(
+    ///   sums,
+    ///   arr2(&[[27, 45, 19],
+    ///          [111, 129, 47],
+    ///          [87, 96, 34]]));
+    /// ```
+    pub fn chunks<E>(&self, chunk_size: E) -> Chunks<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: Data,
+    {
+        Chunks::new(self.view(), chunk_size)
+    }
+
+    /// Return a chunks producer (and iterable).
+    ///
+    /// It produces the chunks of a given n-dimensional chunk size, covering the
+    /// whole array: where the array's length along an axis is not a multiple of the
+    /// chunk size, the last chunks along that axis are smaller.
+    ///
+    /// The produced element is a `ArrayViewMut<A, D>` with the dimension
+    /// `chunk_size`, or smaller at the edges of the array.
+    ///
+    /// **Panics** if any dimension of `chunk_size` is zero<br>
+    /// (**Panics** if `D` is `IxDyn` and `chunk_size` does not match the
+    /// number of array axes.)
+    ///
+    /// ```rust
+    /// use ndarray::Array;
+    /// use ndarray::arr2;
+    /// let mut a = Array::zeros((5, 7));
+    ///
+    /// // Fill each 2 × 3 chunk with the index of where it appeared in iteration
+    /// for (i, mut chunk) in a.chunks_mut((2, 3)).into_iter().enumerate() {
+    ///     chunk.fill(i);
+    /// }
+    ///
+    /// assert_eq!(
+    ///   a,
+    ///   arr2(&[[0, 0, 0, 1, 1, 1, 2],
+    ///          [0, 0, 0, 1, 1, 1, 2],
+    ///          [3, 3, 3, 4, 4, 4, 5],
+    ///          [3, 3, 3, 4, 4, 4, 5],
+    ///          [6, 6, 6, 7, 7, 7, 8]]));
+    /// ```
+    pub fn chunks_mut<E>(&mut self, chunk_size: E) -> ChunksMut<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: DataMut,
+    {
+        ChunksMut::new(self.view_mut(), chunk_size)
+    }
+
     /// Return a window producer and iterable.
     ///
     /// The windows are all distinct overlapping views of size `window_size`
@@ -1399,6 +1536,69 @@ where
         Windows::new(self.view(), window_size)
     }
 
+    /// Return a window producer and iterable, with the windows `stride` elements
+    /// apart along each axis.
+    ///
+    /// The windows are views of size `window_size` that fit into the array's shape,
+    /// like the windows of [`.windows()`](Self::windows), but only every `stride`th
+    /// window along each axis is produced, starting with the first. With a stride
+    /// equal to the window size the windows do not overlap.
+    ///
+    /// The produced element is an `ArrayView<A, D>` with exactly the dimension
+    /// `window_size`.
+    ///
+    /// **Panics** if any dimension of `window_size` or `stride` is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `window_size` or `stride` does not match the
+    /// number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, Zip};
+    ///
+    /// let a = Array2::from_shape_fn((5, 5), |(i, j)| i * 5 + j);
+    ///
+    /// // 3 × 3 windows, two elements apart
+    /// let maxima = Zip::from(a.windows_with_stride((3, 3), (2, 2)))
+    ///     .map_collect(|w| *w.iter().max().unwrap());
+    /// assert_eq!(maxima, array![[12, 14], [22, 24]]);
+    /// ```
+    pub fn windows_with_stride<E>(&self, window_size: E, stride: E) -> Windows<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: Data,
+    {
+        Windows::new_with_stride(self.view(), window_size, stride)
+    }
+
+    /// Return a producer and iterable of dilated windows.
+    ///
+    /// A dilated window has `window_size` elements, that are `dilation` elements
+    /// apart along each axis, so that a window covers
+    /// `(window_size - 1) * dilation + 1` elements of the array along each axis.
+    /// Like [`.windows()`](Self::windows), all such windows that fit into the array's
+    /// shape are produced, one element apart.
+    ///
+    /// The produced element is an `ArrayView<A, D>` with exactly the dimension
+    /// `window_size`.
+    ///
+    /// **Panics** if any dimension of `window_size` or `dilation` is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `window_size` or `dilation` does not match the
+    /// number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{array, Array1};
+    ///
+    /// let a = Array1::from_iter(0..7);
+    /// let windows: Vec<_> = a.windows_dilated(3, 2).into_iter().collect();
+    /// assert_eq!(windows, [array![0, 2, 4], array![1, 3, 5], array![2, 4, 6]]);
+    /// ```
+    pub fn windows_dilated<E>(&self, window_size: E, dilation: E) -> Windows<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: Data,
+    {
+        Windows::new_dilated(self.view(), window_size, dilation)
+    }
+
     // Return (length, stride) for diagonal
     fn diag_params(&self) -> (Ix, Ixs) {
         /* empty shape has len 1 */
@@ -1728,7 +1928,7 @@ where
     {
         let len = self.dim.size();
         if size_of_shape_checked(&shape) != Ok(len) {
-            return Err(error::incompatible_shapes(&self.dim, &shape));
+            return Err(error::incompatible_shapes(&self.dim, &shape).with_operation("to_shape"));
         }
 
         // Create a view if the length is 0, safe because the array and new shape is empty.
@@ -1744,7 +1944,7 @@ where
                 return Ok(CowArray::from(ArrayView::new(self.ptr, shape, to_strides)));
             }
             Err(err) if err.kind() == ErrorKind::IncompatibleShape => {
-                return Err(error::incompatible_shapes(&self.dim, &shape));
+                return Err(error::incompatible_shapes(&self.dim, &shape).with_operation("to_shape"));
             }
             _otherwise => { }
         }
@@ -1782,7 +1982,7 @@ where
     {
         let shape = shape.into_dimension();
         if size_of_shape_checked(&shape) != Ok(self.dim.size()) {
-            return Err(error::incompatible_shapes(&self.dim, &shape));
+            return Err(error::incompatible_shapes(&self.dim, &shape).with_operation("into_shape"));
         }
         // Check if contiguous, if not => copy all, else just adapt strides
         unsafe {
@@ -1792,7 +1992,8 @@ where
             } else if self.ndim() > 1 && self.raw_view().reversed_axes().is_standard_layout() {
                 Ok(self.with_strides_dim(shape.fortran_strides(), shape))
             } else {
-                Err(error::from_kind(error::ErrorKind::IncompatibleLayout))
+                Err(error::from_kind(error::ErrorKind::IncompatibleLayout)
+                    .with_operation("into_shape"))
             }
         }
     }
@@ -2125,12 +2326,23 @@ where
         axes_of(&self.dim, &self.strides)
     }
 
-    /*
-    /// Return the axis with the least stride (by absolute value)
+    /// Return the axis with the least stride (by absolute value),
+    /// preferring axes with len > 1.
+    ///
+    /// This is the axis whose lanes are closest to contiguous in memory, which
+    /// makes it the most efficient lane axis for [`Zip::lanes`](crate::Zip::lanes).
+    ///
+    /// ***Panics*** if the array is zero-dimensional.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis, ShapeBuilder};
+    ///
+    /// assert_eq!(Array::<f32, _>::zeros((3, 4)).min_stride_axis(), Axis(1));
+    /// assert_eq!(Array::<f32, _>::zeros((3, 4).f()).min_stride_axis(), Axis(0));
+    /// ```
     pub fn min_stride_axis(&self) -> Axis {
         self.dim.min_stride_axis(&self.strides)
     }
-    */
 
     /// Return the axis with the greatest stride (by absolute value),
     /// preferring axes with len > 1.
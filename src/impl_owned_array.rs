@@ -11,7 +11,7 @@ use crate::dimension;
 use crate::error::{ErrorKind, ShapeError};
 use crate::iterators::Baseiter;
 use crate::low_level_util::AbortIfPanic;
-use crate::OwnedRepr;
+use crate::{ArrayAllocator, OwnedRepr};
 use crate::Zip;
 
 /// Methods specific to `Array0`.
@@ -19,7 +19,10 @@ use crate::Zip;
 /// ***See also all methods for [`ArrayBase`]***
 ///
 /// [`ArrayBase`]: struct.ArrayBase.html
-impl<A> Array<A, Ix0> {
+impl<A, Al> Array<A, Ix0, Al>
+where
+    Al: ArrayAllocator,
+{
     /// Returns the single element in the array without cloning it.
     ///
     /// ```
@@ -58,18 +61,44 @@ impl<A> Array<A, Ix0> {
 /// ***See also all methods for [`ArrayBase`]***
 ///
 /// [`ArrayBase`]: struct.ArrayBase.html
-impl<A, D> Array<A, D>
+impl<A, D, Al> Array<A, D, Al>
 where
     D: Dimension,
+    Al: ArrayAllocator,
 {
     /// Return a vector of the elements in the array, in the way they are
     /// stored internally.
     ///
     /// If the array is in standard memory layout, the logical element order
     /// of the array (`.iter()` order) and of the returned vector will be the same.
+    ///
+    /// If the array's storage is over-aligned (see
+    /// [`zeros_aligned`](ArrayBase::zeros_aligned)), the elements are moved to a new vector,
+    /// since a `Vec` can't take over an over-aligned allocation. The same applies to
+    /// arrays that use another allocator than the global allocator; use
+    /// [`into_raw_parts_with_alloc`](ArrayBase::into_raw_parts_with_alloc) to keep the
+    /// allocation.
     pub fn into_raw_vec(self) -> Vec<A> {
         self.data.into_vec()
     }
+
+    /// Decompose the array's storage into its raw components: a pointer to the
+    /// allocation, the number of elements, the capacity in elements and the allocator.
+    ///
+    /// The elements are stored as they are internally, in the same way as for
+    /// [`into_raw_vec`](ArrayBase::into_raw_vec). The caller becomes responsible for
+    /// dropping the elements and for deallocating the memory, which was allocated by the
+    /// allocator with the layout of `capacity` elements and the alignment given by
+    /// [`.alignment()`](ArrayBase::alignment).
+    pub fn into_raw_parts_with_alloc(self) -> (*mut A, usize, usize, Al) {
+        let (ptr, len, capacity, alloc) = self.data.into_raw_parts_with_alloc();
+        (ptr.as_ptr(), len, capacity, alloc)
+    }
+
+    /// Return a reference to the allocator of the array's storage.
+    pub fn allocator(&self) -> &Al {
+        self.data.allocator()
+    }
 }
 
 /// Methods specific to `Array2`.
@@ -77,7 +106,10 @@ where
 /// ***See also all methods for [`ArrayBase`]***
 ///
 /// [`ArrayBase`]: struct.ArrayBase.html
-impl<A> Array<A, Ix2> {
+impl<A, Al> Array<A, Ix2, Al>
+where
+    Al: ArrayAllocator + Clone,
+{
     /// Append a row to an array
     ///
     /// The elements from `row` are cloned and added as a new row in the array.
@@ -122,6 +154,7 @@ impl<A> Array<A, Ix2> {
         A: Clone,
     {
         self.append(Axis(0), row.insert_axis(Axis(0)))
+            .map_err(|e| e.with_operation("push_row"))
     }
 
     /// Append a column to an array
@@ -168,11 +201,14 @@ impl<A> Array<A, Ix2> {
         A: Clone,
     {
         self.append(Axis(1), column.insert_axis(Axis(1)))
+            .map_err(|e| e.with_operation("push_column"))
     }
 }
 
-impl<A, D> Array<A, D>
-    where D: Dimension
+impl<A, D, Al> Array<A, D, Al>
+where
+    D: Dimension,
+    Al: ArrayAllocator,
 {
     /// Move all elements from self into `new_array`, which must be of the same shape but
     /// can have a different memory layout. The destination is overwritten completely.
@@ -279,7 +315,7 @@ impl<A, D> Array<A, D>
     /// # Safety
     ///
     /// This is a panic critical section since `self` is already moved-from.
-    fn drop_unreachable_elements(mut self) -> OwnedRepr<A> {
+    fn drop_unreachable_elements(mut self) -> OwnedRepr<A, Al> {
         let self_len = self.len();
 
         // "deconstruct" self; the owned repr releases ownership of all elements and we
@@ -299,7 +335,7 @@ impl<A, D> Array<A, D>
 
     #[inline(never)]
     #[cold]
-    fn drop_unreachable_elements_slow(mut self) -> OwnedRepr<A> {
+    fn drop_unreachable_elements_slow(mut self) -> OwnedRepr<A, Al> {
         // "deconstruct" self; the owned repr releases ownership of all elements and we
         // carry on with raw view methods
         let data_len = self.data.len();
@@ -320,27 +356,34 @@ impl<A, D> Array<A, D>
     /// Create an empty array with an all-zeros shape
     ///
     /// ***Panics*** if D is zero-dimensional, because it can't be empty
-    pub(crate) fn empty() -> Array<A, D> {
+    pub(crate) fn empty_in(alloc: Al) -> Self {
         assert_ne!(D::NDIM, Some(0));
         let ndim = D::NDIM.unwrap_or(1);
-        Array::from_shape_simple_fn(D::zeros(ndim), || unreachable!())
+        let data = OwnedRepr::with_capacity_aligned_in(0, mem::align_of::<A>(), alloc);
+        unsafe { Self::from_repr_unchecked(D::zeros(ndim), data) }
     }
 
     /// Create new_array with the right layout for appending to `growing_axis`
     #[cold]
-    fn change_to_contig_append_layout(&mut self, growing_axis: Axis) {
+    fn change_to_contig_append_layout(&mut self, growing_axis: Axis)
+    where
+        Al: Clone,
+    {
         let ndim = self.ndim();
         let mut dim = self.raw_dim();
 
         // The array will be created with 0 (C) or ndim-1 (F) as the biggest stride
         // axis. Rearrange the shape so that `growing_axis` is the biggest stride axis
         // afterwards.
+        // keep the alignment and the allocator of the storage
+        let align = self.data.alignment();
+        let alloc = self.data.allocator().clone();
         let mut new_array;
         if growing_axis == Axis(ndim - 1) {
-            new_array = Self::uninit(dim.f());
+            new_array = Self::uninit_aligned_in(dim.f(), align, alloc.clone());
         } else {
             dim.slice_mut()[..=growing_axis.index()].rotate_right(1);
-            new_array = Self::uninit(dim);
+            new_array = Self::uninit_aligned_in(dim, align, alloc.clone());
             new_array.dim.slice_mut()[..=growing_axis.index()].rotate_left(1);
             new_array.strides.slice_mut()[..=growing_axis.index()].rotate_left(1);
         }
@@ -348,7 +391,7 @@ impl<A, D> Array<A, D>
         // self -> old_self.
         // dummy array -> self.
         // old_self elements are moved -> new_array.
-        let old_self = std::mem::replace(self, Self::empty());
+        let old_self = std::mem::replace(self, Self::empty_in(alloc));
         old_self.move_into_uninit(new_array.view_mut());
 
         // new_array -> self.
@@ -412,9 +455,11 @@ impl<A, D> Array<A, D>
     where
         A: Clone,
         D: RemoveAxis,
+        Al: Clone,
     {
         // same-dimensionality conversion
         self.append(axis, array.insert_axis(axis).into_dimensionality::<D>().unwrap())
+            .map_err(|e| e.with_operation("push"))
     }
 
 
@@ -473,9 +518,11 @@ impl<A, D> Array<A, D>
     where
         A: Clone,
         D: RemoveAxis,
+        Al: Clone,
     {
         if self.ndim() == 0 {
-            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape)
+                .with_operation("append"));
         }
 
         let current_axis_len = self.len_of(axis);
@@ -485,7 +532,10 @@ impl<A, D> Array<A, D>
         let array_rem_shape = array_dim.remove_axis(axis);
 
         if remaining_shape != array_rem_shape {
-            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape)
+                .with_operation("append")
+                .with_shapes(self_dim.slice(), array_dim.slice())
+                .with_axis(axis));
         }
 
         let len_to_append = array.len();
@@ -623,12 +673,12 @@ impl<A, D> Array<A, D>
             // on scope exit (panic or loop finish). This "indirect" way to
             // write the length is used to help the compiler, the len store to self.data may
             // otherwise be mistaken to alias with other stores in the loop.
-            struct SetLenOnDrop<'a, A: 'a> {
+            struct SetLenOnDrop<'a, A: 'a, Al: ArrayAllocator> {
                 len: usize,
-                data: &'a mut OwnedRepr<A>,
+                data: &'a mut OwnedRepr<A, Al>,
             }
 
-            impl<A> Drop for SetLenOnDrop<'_, A> {
+            impl<A, Al: ArrayAllocator> Drop for SetLenOnDrop<'_, A, Al> {
                 fn drop(&mut self) {
                     unsafe {
                         self.data.set_len(self.len);
@@ -1,4 +1,8 @@
+use std::marker::PhantomData;
+
 use crate::imp_prelude::*;
+use crate::indexes::IndicesIter;
+use crate::split_at::SplitAt;
 use crate::ElementsBase;
 use crate::ElementsBaseMut;
 use crate::IntoDimension;
@@ -229,3 +233,285 @@ pub struct ExactChunksIterMut<'a, A, D> {
     chunk: D,
     inner_strides: D,
 }
+
+/// Chunks producer and iterable.
+///
+/// See [`.chunks()`](../struct.ArrayBase.html#method.chunks) for more
+/// information.
+pub struct Chunks<'a, A, D> {
+    base: RawArrayView<A, D>,
+    chunk: D,
+    /// The row major strides of the indices of all the chunks
+    index_strides: D,
+    /// The linear index of the first chunk
+    start: usize,
+    dim: D,
+    life: PhantomData<&'a A>,
+}
+
+/// Chunks producer and iterable.
+///
+/// See [`.chunks_mut()`](../struct.ArrayBase.html#method.chunks_mut)
+/// for more information.
+pub struct ChunksMut<'a, A, D> {
+    base: RawArrayViewMut<A, D>,
+    chunk: D,
+    /// The row major strides of the indices of all the chunks
+    index_strides: D,
+    /// The linear index of the first chunk
+    start: usize,
+    dim: D,
+    life: PhantomData<&'a mut A>,
+}
+
+/// Return the number of chunks along each axis, rounding up.
+///
+/// **Panics** if any chunk dimension is zero or the dimensionalities do not match.
+fn chunks_dim<D: Dimension>(dim: &D, chunk: &D) -> D {
+    ndassert!(
+        dim.ndim() == chunk.ndim(),
+        concat!(
+            "Chunk dimension {} does not match array dimension {} ",
+            "(with array of shape {:?})"
+        ),
+        chunk.ndim(),
+        dim.ndim(),
+        dim.slice()
+    );
+    let mut n_chunks = dim.clone();
+    for i in 0..dim.ndim() {
+        assert_ne!(chunk[i], 0, "chunk size must not be zero!");
+        let (whole, rem) = (dim[i] / chunk[i], dim[i] % chunk[i]);
+        n_chunks[i] = whole + (rem > 0) as usize;
+    }
+    n_chunks
+}
+
+/// Return the pointer to the first element and the shape of the chunk with the
+/// linear index `index`; the chunks at the end of an axis are cut off by the array's
+/// shape.
+///
+/// **Safety:** `index` must be in bounds for the chunks of the array.
+unsafe fn chunk_parts<A, D: Dimension>(ptr: *mut A, dim: &D, strides: &D, chunk: &D,
+                                       index_strides: &D, mut index: usize) -> (*mut A, D)
+{
+    let mut first = chunk.clone();
+    let mut chunk_dim = chunk.clone();
+    for i in 0..dim.ndim() {
+        first[i] *= index / index_strides[i];
+        index %= index_strides[i];
+        chunk_dim[i] = usize::min(chunk[i], dim[i] - first[i]);
+    }
+    (ptr.offset(D::stride_offset(&first, strides)), chunk_dim)
+}
+
+macro_rules! chunks_producer_impl {
+    ($name:ident, $iter_name:ident, $item:ident) => {
+impl<'a, A, D> NdProducer for $name<'a, A, D>
+where
+    D: Dimension,
+{
+    type Item = $item<'a, A, D>;
+    type Dim = D;
+    type Ptr = usize;
+    type Stride = usize;
+
+    private_impl! {}
+
+    #[doc(hidden)]
+    fn raw_dim(&self) -> Self::Dim {
+        self.dim.clone()
+    }
+
+    #[doc(hidden)]
+    fn equal_dim(&self, dim: &Self::Dim) -> bool {
+        self.dim.equal(dim)
+    }
+
+    #[doc(hidden)]
+    fn as_ptr(&self) -> Self::Ptr {
+        self.start
+    }
+
+    #[doc(hidden)]
+    fn layout(&self) -> Layout {
+        if self.dim.ndim() <= 1 {
+            Layout::one_dimensional()
+        } else {
+            Layout::none()
+        }
+    }
+
+    #[doc(hidden)]
+    unsafe fn as_ref(&self, ptr: Self::Ptr) -> Self::Item {
+        let (chunk_ptr, chunk_dim) = chunk_parts(self.base.ptr.as_ptr(), &self.base.dim,
+                                                 &self.base.strides, &self.chunk,
+                                                 &self.index_strides, ptr);
+        $item::new_(chunk_ptr, chunk_dim, self.base.strides.clone())
+    }
+
+    #[doc(hidden)]
+    unsafe fn uget_ptr(&self, i: &Self::Dim) -> Self::Ptr {
+        let offset: usize = i.slice().iter().zip(self.index_strides.slice())
+                                    .map(|(i, s)| i * s)
+                                    .sum();
+        self.start + offset
+    }
+
+    #[doc(hidden)]
+    fn stride_of(&self, axis: Axis) -> Self::Stride {
+        self.index_strides[axis.index()]
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride {
+        // the step of the linear index along the last axis
+        1
+    }
+
+    #[doc(hidden)]
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+        let start_b = self.start + index * self.index_strides[axis.index()];
+        let (a, b) = self.dim.split_at(axis, index);
+        (
+            $name {
+                base: self.base.clone(),
+                chunk: self.chunk.clone(),
+                index_strides: self.index_strides.clone(),
+                start: self.start,
+                dim: a,
+                life: PhantomData,
+            },
+            $name {
+                base: self.base,
+                chunk: self.chunk,
+                index_strides: self.index_strides,
+                start: start_b,
+                dim: b,
+                life: PhantomData,
+            },
+        )
+    }
+}
+
+impl<'a, A, D> IntoIterator for $name<'a, A, D>
+where
+    D: Dimension,
+    A: 'a,
+{
+    type Item = $item<'a, A, D>;
+    type IntoIter = $iter_name<'a, A, D>;
+    fn into_iter(self) -> Self::IntoIter {
+        $iter_name {
+            iter: crate::indices(self.dim.clone()).into_iter(),
+            chunks: self,
+        }
+    }
+}
+
+impl<'a, A, D> Iterator for $iter_name<'a, A, D>
+where
+    D: Dimension,
+{
+    type Item = $item<'a, A, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunks = &self.chunks;
+        self.iter.next().map(|index| unsafe {
+            chunks.as_ref(chunks.uget_ptr(&index.into_dimension()))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, A, D> ExactSizeIterator for $iter_name<'a, A, D>
+where
+    D: Dimension,
+{
+}
+    };
+}
+
+chunks_producer_impl!(Chunks, ChunksIter, ArrayView);
+chunks_producer_impl!(ChunksMut, ChunksIterMut, ArrayViewMut);
+
+impl<'a, A, D: Dimension> Chunks<'a, A, D> {
+    /// Creates a new chunks producer.
+    ///
+    /// **Panics** if any chunk dimension is zero
+    pub(crate) fn new<E>(a: ArrayView<'a, A, D>, chunk: E) -> Self
+    where
+        E: IntoDimension<Dim = D>,
+    {
+        let chunk = chunk.into_dimension();
+        let dim = chunks_dim(&a.dim, &chunk);
+        Chunks {
+            base: a.into_raw_view(),
+            chunk,
+            index_strides: dim.default_strides(),
+            start: 0,
+            dim,
+            life: PhantomData,
+        }
+    }
+}
+
+impl<'a, A, D: Clone> Clone for Chunks<'a, A, D> {
+    fn clone(&self) -> Self {
+        Chunks {
+            base: self.base.clone(),
+            chunk: self.chunk.clone(),
+            index_strides: self.index_strides.clone(),
+            start: self.start,
+            dim: self.dim.clone(),
+            life: PhantomData,
+        }
+    }
+}
+
+impl<'a, A, D: Dimension> ChunksMut<'a, A, D> {
+    /// Creates a new chunks producer.
+    ///
+    /// **Panics** if any chunk dimension is zero
+    pub(crate) fn new<E>(a: ArrayViewMut<'a, A, D>, chunk: E) -> Self
+    where
+        E: IntoDimension<Dim = D>,
+    {
+        let chunk = chunk.into_dimension();
+        let dim = chunks_dim(&a.dim, &chunk);
+        ChunksMut {
+            base: a.into_raw_view_mut(),
+            chunk,
+            index_strides: dim.default_strides(),
+            start: 0,
+            dim,
+            life: PhantomData,
+        }
+    }
+}
+
+/// Chunks iterator.
+///
+/// See [`.chunks()`](../struct.ArrayBase.html#method.chunks) for more
+/// information.
+pub struct ChunksIter<'a, A, D> {
+    iter: IndicesIter<D>,
+    chunks: Chunks<'a, A, D>,
+}
+
+/// Chunks iterator.
+///
+/// See [`.chunks_mut()`](../struct.ArrayBase.html#method.chunks_mut)
+/// for more information.
+pub struct ChunksIterMut<'a, A, D> {
+    iter: IndicesIter<D>,
+    chunks: ChunksMut<'a, A, D>,
+}
+
+send_sync_read_only!(Chunks);
+send_sync_read_only!(ChunksIter);
+send_sync_read_write!(ChunksMut);
+send_sync_read_write!(ChunksIterMut);
@@ -10,18 +10,19 @@ use std::mem;
 use std::ptr::NonNull;
 
 use crate::imp_prelude::*;
-use crate::OwnedRepr;
+use crate::{ArrayAllocator, Global, OwnedRepr};
 
 use super::Baseiter;
 use crate::impl_owned_array::drop_unreachable_raw;
 
 
 /// By-value iterator for an array
-pub struct IntoIter<A, D>
+pub struct IntoIter<A, D, Al = Global>
 where
     D: Dimension,
+    Al: ArrayAllocator,
 {
-    array_data: OwnedRepr<A>,
+    array_data: OwnedRepr<A, Al>,
     inner: Baseiter<A, D>,
     data_len: usize,
     /// first memory address of an array element
@@ -31,12 +32,13 @@ where
     has_unreachable_elements: bool,
 }
 
-impl<A, D> IntoIter<A, D> 
+impl<A, D, Al> IntoIter<A, D, Al>
 where
     D: Dimension,
+    Al: ArrayAllocator,
 {
     /// Create a new by-value iterator that consumes `array`
-    pub(crate) fn new(mut array: Array<A, D>) -> Self {
+    pub(crate) fn new(mut array: Array<A, D, Al>) -> Self {
         unsafe {
             let array_head_ptr = array.ptr;
             let ptr = array.as_mut_ptr();
@@ -57,7 +59,7 @@ where
     }
 }
 
-impl<A, D: Dimension> Iterator for IntoIter<A, D> {
+impl<A, D: Dimension, Al: ArrayAllocator> Iterator for IntoIter<A, D, Al> {
     type Item = A;
 
     #[inline]
@@ -70,13 +72,14 @@ impl<A, D: Dimension> Iterator for IntoIter<A, D> {
     }
 }
 
-impl<A, D: Dimension> ExactSizeIterator for IntoIter<A, D> {
+impl<A, D: Dimension, Al: ArrayAllocator> ExactSizeIterator for IntoIter<A, D, Al> {
     fn len(&self) -> usize { self.inner.len() }
 }
 
-impl<A, D> Drop for IntoIter<A, D>
+impl<A, D, Al> Drop for IntoIter<A, D, Al>
 where
-    D: Dimension
+    D: Dimension,
+    Al: ArrayAllocator,
 {
     fn drop(&mut self) {
         if !self.has_unreachable_elements || mem::size_of::<A>() == 0 || !mem::needs_drop::<A>() {
@@ -97,12 +100,13 @@ where
     }
 }
 
-impl<A, D> IntoIterator for Array<A, D>
+impl<A, D, Al> IntoIterator for Array<A, D, Al>
 where
-    D: Dimension
+    D: Dimension,
+    Al: ArrayAllocator,
 {
     type Item = A;
-    type IntoIter = IntoIter<A, D>;
+    type IntoIter = IntoIter<A, D, Al>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter::new(self)
@@ -24,7 +24,10 @@ use crate::Ix1;
 use super::{ArrayBase, ArrayView, ArrayViewMut, Axis, Data, NdProducer, RemoveAxis};
 use super::{Dimension, Ix, Ixs};
 
-pub use self::chunks::{ExactChunks, ExactChunksIter, ExactChunksIterMut, ExactChunksMut};
+pub use self::chunks::{
+    Chunks, ChunksIter, ChunksIterMut, ChunksMut, ExactChunks, ExactChunksIter,
+    ExactChunksIterMut, ExactChunksMut,
+};
 pub use self::lanes::{Lanes, LanesMut};
 pub use self::windows::Windows;
 pub use self::into_iter::IntoIter;
@@ -1449,6 +1452,7 @@ pub unsafe trait TrustedIterator {}
 
 use crate::indexes::IndicesIterF;
 use crate::iter::IndicesIter;
+use crate::ArrayAllocator;
 #[cfg(feature = "std")]
 use crate::{geomspace::Geomspace, linspace::Linspace, logspace::Logspace};
 #[cfg(feature = "std")]
@@ -1467,7 +1471,12 @@ unsafe impl TrustedIterator for ::std::ops::Range<usize> {}
 // FIXME: These indices iter are dubious -- size needs to be checked up front.
 unsafe impl<D> TrustedIterator for IndicesIter<D> where D: Dimension {}
 unsafe impl<D> TrustedIterator for IndicesIterF<D> where D: Dimension {}
-unsafe impl<A, D> TrustedIterator for IntoIter<A, D> where D: Dimension {}
+unsafe impl<A, D, Al> TrustedIterator for IntoIter<A, D, Al>
+where
+    D: Dimension,
+    Al: ArrayAllocator,
+{
+}
 
 /// Like Iterator::collect, but only for trusted length iterators
 pub fn to_vec<I>(iter: I) -> Vec<I::Item>
@@ -6,7 +6,9 @@ use crate::NdProducer;
 
 /// Window producer and iterable
 ///
-/// See [`.windows()`](../struct.ArrayBase.html#method.windows) for more
+/// See [`.windows()`](../struct.ArrayBase.html#method.windows),
+/// [`.windows_with_stride()`](../struct.ArrayBase.html#method.windows_with_stride) and
+/// [`.windows_dilated()`](../struct.ArrayBase.html#method.windows_dilated) for more
 /// information.
 pub struct Windows<'a, A, D> {
     base: ArrayView<'a, A, D>,
@@ -20,6 +22,33 @@ impl<'a, A, D: Dimension> Windows<'a, A, D> {
         E: IntoDimension<Dim = D>,
     {
         let window = window_size.into_dimension();
+        let ones = ones_dim::<D>(window.ndim());
+        Windows::new_with_stride_dilation(a, window, ones.clone(), ones)
+    }
+
+    pub(crate) fn new_with_stride<E>(a: ArrayView<'a, A, D>, window_size: E, stride: E) -> Self
+    where
+        E: IntoDimension<Dim = D>,
+    {
+        let window = window_size.into_dimension();
+        let ones = ones_dim::<D>(window.ndim());
+        Windows::new_with_stride_dilation(a, window, stride.into_dimension(), ones)
+    }
+
+    pub(crate) fn new_dilated<E>(a: ArrayView<'a, A, D>, window_size: E, dilation: E) -> Self
+    where
+        E: IntoDimension<Dim = D>,
+    {
+        let window = window_size.into_dimension();
+        let ones = ones_dim::<D>(window.ndim());
+        Windows::new_with_stride_dilation(a, window, ones, dilation.into_dimension())
+    }
+
+    /// Windows of size `window`, with starting points `stride` elements apart, and
+    /// with the elements of a window `dilation` elements apart (all per axis).
+    pub(crate) fn new_with_stride_dilation(a: ArrayView<'a, A, D>, window: D, stride: D,
+                                           dilation: D) -> Self
+    {
         ndassert!(
             a.ndim() == window.ndim(),
             concat!(
@@ -30,18 +59,36 @@ impl<'a, A, D: Dimension> Windows<'a, A, D> {
             a.ndim(),
             a.shape()
         );
-        let mut size = a.dim;
-        for (sz, &ws) in size.slice_mut().iter_mut().zip(window.slice()) {
+        ndassert!(
+            a.ndim() == stride.ndim() && a.ndim() == dilation.ndim(),
+            "Window stride and dilation dimensions {} and {} do not match array dimension {}",
+            stride.ndim(),
+            dilation.ndim(),
+            a.ndim()
+        );
+        let mut size = a.dim.clone();
+        let mut base_strides = a.strides.clone();
+        let mut window_strides = a.strides.clone();
+        for i in 0..a.ndim() {
+            let (ws, st, dil) = (window[i], stride[i], dilation[i]);
             assert_ne!(ws, 0, "window-size must not be zero!");
+            assert_ne!(st, 0, "window stride must not be zero!");
+            assert_ne!(dil, 0, "window dilation must not be zero!");
+            // the extent of the window in the array, from its first to last element
+            let extent = (ws - 1)
+                .checked_mul(dil)
+                .and_then(|e| e.checked_add(1))
+                .unwrap_or(usize::MAX);
             // cannot use std::cmp::max(0, ..) since arithmetic underflow panics
-            *sz = if *sz < ws { 0 } else { *sz - ws + 1 };
+            size[i] = if size[i] < extent { 0 } else { (size[i] - extent) / st + 1 };
+            let s = a.strides[i] as isize;
+            base_strides[i] = s.wrapping_mul(st as isize) as usize;
+            window_strides[i] = s.wrapping_mul(dil as isize) as usize;
         }
 
-        let window_strides = a.strides.clone();
-
         unsafe {
             Windows {
-                base: ArrayView::new(a.ptr, size, a.strides),
+                base: ArrayView::new(a.ptr, size, base_strides),
                 window,
                 strides: window_strides,
             }
@@ -49,6 +96,14 @@ impl<'a, A, D: Dimension> Windows<'a, A, D> {
     }
 }
 
+fn ones_dim<D: Dimension>(ndim: usize) -> D {
+    let mut dim = D::zeros(ndim);
+    for x in dim.slice_mut() {
+        *x = 1;
+    }
+    dim
+}
+
 impl_ndproducer! {
     ['a, A, D: Dimension]
     [Clone => 'a, A, D: Clone ]
@@ -86,7 +141,9 @@ where
 
 /// Window iterator.
 ///
-/// See [`.windows()`](../struct.ArrayBase.html#method.windows) for more
+/// See [`.windows()`](../struct.ArrayBase.html#method.windows),
+/// [`.windows_with_stride()`](../struct.ArrayBase.html#method.windows_with_stride) and
+/// [`.windows_dilated()`](../struct.ArrayBase.html#method.windows_dilated) for more
 /// information.
 pub struct WindowsIter<'a, A, D> {
     iter: ElementsBase<'a, A, D>,
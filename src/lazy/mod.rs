@@ -0,0 +1,514 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Lazy elementwise expressions.
+//!
+//! Arithmetic with arrays like `&a + &b * &c` computes one operation at a time, and
+//! allocates a new array for each intermediate result. A lazy expression only records
+//! the operations; all of them are computed together, in a single pass over the
+//! elements, when the expression is evaluated into a new array with
+//! [`.eval()`](Lazy::eval) or into an existing array with
+//! [`.assign_to()`](Lazy::assign_to).
+//!
+//! Lazy expressions are created from arrays with [`.lazy()`](ArrayBase::lazy) and
+//! combined with the arithmetic operators, with other lazy expressions or with
+//! scalars, and with [`.map()`](Lazy::map) and [`.zip_with()`](Lazy::zip_with).
+//! Like in array arithmetic, the operands are broadcast to a common shape.
+//!
+//! ```
+//! use ndarray::{array, Array2};
+//!
+//! let a = array![[1., 2.], [3., 4.]];
+//! let b = array![10., 20.];
+//! let c = Array2::from_elem((2, 2), 0.5);
+//!
+//! // records the operations; nothing is computed yet
+//! let expr = a.lazy() + b.lazy() * c.lazy() - 1.;
+//! assert_eq!(expr.eval(), array![[5., 11.], [7., 13.]]);
+//!
+//! // evaluate into an existing array
+//! let mut out = Array2::zeros((2, 2));
+//! (-a.lazy() * 2.).map(f64::abs).assign_to(&mut out);
+//! assert_eq!(out, array![[2., 4.], [6., 8.]]);
+//! ```
+
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+
+use num_complex::Complex;
+
+use crate::dimension::broadcast::co_broadcast;
+use crate::dimension::DimMax;
+use crate::imp_prelude::*;
+use crate::{NdProducer, ScalarOperand, ShapeError, Zip};
+
+mod producers;
+
+use self::producers::{ClonedProducer, MapProducer, ScalarProducer, ZipProducer};
+
+/// An elementwise expression, the contents of a [`Lazy`].
+///
+/// This trait can not be implemented outside of ndarray.
+pub trait Expression {
+    /// The element type of the result
+    type Elem;
+    /// The dimension type of the result
+    type Dim: Dimension;
+    /// Return the shape of the result.
+    fn raw_dim(&self) -> Self::Dim;
+
+    private_decl! {}
+}
+
+/// An expression that can be evaluated in the shape `E`, with its operands broadcast
+/// to that shape.
+///
+/// This trait can not be implemented outside of ndarray.
+pub trait BroadcastExpression<E: Dimension>: Expression {
+    #[doc(hidden)]
+    type Producer: NdProducer<Item = Self::Elem, Dim = E>;
+
+    /// **Panics** if the expression can not be broadcast to `shape`.
+    #[doc(hidden)]
+    fn broadcast_producer(self, shape: &E) -> Self::Producer;
+}
+
+/// A lazy elementwise expression.
+///
+/// See the [module documentation](self) for more information.
+#[derive(Clone)]
+pub struct Lazy<E> {
+    expr: E,
+}
+
+/// The elements of an array view, cloned.
+pub struct ViewExpr<'a, A, D> {
+    view: ArrayView<'a, A, D>,
+}
+
+/// A scalar, which broadcasts to any shape.
+#[derive(Clone)]
+pub struct ScalarExpr<B> {
+    value: B,
+}
+
+/// The function `f` applied to each element of an expression.
+#[derive(Clone)]
+pub struct MapExpr<E, F> {
+    expr: E,
+    f: F,
+}
+
+/// The function `f` applied to each pair of elements of two expressions.
+#[derive(Clone)]
+pub struct ZipExpr<E1, E2, F> {
+    a: E1,
+    b: E2,
+    f: F,
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a lazy expression of the elements of the array.
+    ///
+    /// Arithmetic with lazy expressions records the operations instead of computing
+    /// them; see the [`lazy`](crate::lazy) module for more information.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1, 2, 3];
+    /// let b = array![[1], [10]];
+    /// assert_eq!((a.lazy() * b.lazy() + 1).eval(), array![[2, 3, 4], [11, 21, 31]]);
+    /// ```
+    pub fn lazy(&self) -> Lazy<ViewExpr<'_, A, D>> {
+        Lazy {
+            expr: ViewExpr { view: self.view() },
+        }
+    }
+}
+
+impl<E> Lazy<E>
+where
+    E: Expression,
+{
+    /// Return the shape of the result of the expression.
+    pub fn raw_dim(&self) -> E::Dim {
+        self.expr.raw_dim()
+    }
+
+    /// Apply `f` to each element of the expression.
+    ///
+    /// The function is called lazily, when the expression is evaluated; it is called
+    /// once for each element of the result, which means that it is called more than
+    /// once per element of the expression if the expression is broadcast.
+    pub fn map<F, T>(self, f: F) -> Lazy<MapExpr<E, F>>
+    where
+        F: Fn(E::Elem) -> T + Clone,
+    {
+        Lazy {
+            expr: MapExpr { expr: self.expr, f },
+        }
+    }
+
+    /// Apply `f` to each pair of elements of the two expressions, which are broadcast to
+    /// a common shape.
+    ///
+    /// The arithmetic operators for lazy expressions use this method.
+    ///
+    /// **Panics** if broadcasting isn't possible.
+    pub fn zip_with<E2, F, T>(self, rhs: Lazy<E2>, f: F) -> Lazy<ZipExpr<E, E2, F>>
+    where
+        E2: Expression,
+        E::Dim: DimMax<E2::Dim>,
+        F: Fn(E::Elem, E2::Elem) -> T + Clone,
+    {
+        let expr = ZipExpr {
+            a: self.expr,
+            b: rhs.expr,
+            f,
+        };
+        // check the shapes here, so that incompatible shapes are found where they
+        // are combined
+        if let Err(err) = expr.shape() {
+            panic!(
+                "ndarray: could not broadcast expressions of shapes {:?} and {:?}: {}",
+                expr.a.raw_dim().slice(),
+                expr.b.raw_dim().slice(),
+                err
+            );
+        }
+        Lazy { expr }
+    }
+
+    /// Evaluate the expression into a new array.
+    pub fn eval(self) -> Array<E::Elem, E::Dim>
+    where
+        E: BroadcastExpression<<E as Expression>::Dim>,
+    {
+        let dim = self.expr.raw_dim();
+        Zip::from(self.expr.broadcast_producer(&dim)).map_collect(|x| x)
+    }
+
+    /// Evaluate the expression into `out`, which can also be an array view.
+    ///
+    /// The expression is broadcast to the shape of `out`.
+    ///
+    /// **Panics** if broadcasting isn't possible.
+    pub fn assign_to<S, D>(self, out: &mut ArrayBase<S, D>)
+    where
+        S: DataMut<Elem = E::Elem>,
+        D: Dimension,
+        E: BroadcastExpression<D>,
+    {
+        let dim = out.raw_dim();
+        Zip::from(out)
+            .and(self.expr.broadcast_producer(&dim))
+            .for_each(|out, x| *out = x);
+    }
+}
+
+impl<'a, A, D> Expression for ViewExpr<'a, A, D>
+where
+    D: Dimension,
+{
+    type Elem = A;
+    type Dim = D;
+
+    fn raw_dim(&self) -> D {
+        self.view.raw_dim()
+    }
+
+    private_impl! {}
+}
+
+impl<'a, A, D, E> BroadcastExpression<E> for ViewExpr<'a, A, D>
+where
+    A: Clone,
+    D: Dimension,
+    E: Dimension,
+{
+    type Producer = ClonedProducer<'a, A, E>;
+
+    fn broadcast_producer(self, shape: &E) -> Self::Producer {
+        let view = match self.view.broadcast(shape.clone()) {
+            Some(view) => view,
+            None => panic!(
+                "ndarray: could not broadcast array from shape: {:?} to: {:?}",
+                self.view.shape(),
+                shape.slice()
+            ),
+        };
+        // the broadcast view borrows `self.view`, but the data lives for 'a
+        let view = unsafe { ArrayView::new(view.ptr, view.dim, view.strides) };
+        ClonedProducer { view }
+    }
+}
+
+impl<'a, A, D: Clone> Clone for ViewExpr<'a, A, D> {
+    fn clone(&self) -> Self {
+        ViewExpr {
+            view: self.view.clone(),
+        }
+    }
+}
+
+impl<B> Expression for ScalarExpr<B> {
+    type Elem = B;
+    type Dim = Ix0;
+
+    fn raw_dim(&self) -> Ix0 {
+        Ix0()
+    }
+
+    private_impl! {}
+}
+
+impl<B, E> BroadcastExpression<E> for ScalarExpr<B>
+where
+    B: Clone,
+    E: Dimension,
+{
+    type Producer = ScalarProducer<B, E>;
+
+    fn broadcast_producer(self, shape: &E) -> Self::Producer {
+        ScalarProducer {
+            value: self.value,
+            dim: shape.clone(),
+        }
+    }
+}
+
+impl<E, F, T> Expression for MapExpr<E, F>
+where
+    E: Expression,
+    F: Fn(E::Elem) -> T + Clone,
+{
+    type Elem = T;
+    type Dim = E::Dim;
+
+    fn raw_dim(&self) -> Self::Dim {
+        self.expr.raw_dim()
+    }
+
+    private_impl! {}
+}
+
+impl<E, F, T, Sh> BroadcastExpression<Sh> for MapExpr<E, F>
+where
+    E: BroadcastExpression<Sh>,
+    F: Fn(E::Elem) -> T + Clone,
+    Sh: Dimension,
+{
+    type Producer = MapProducer<E::Producer, F>;
+
+    fn broadcast_producer(self, shape: &Sh) -> Self::Producer {
+        MapProducer {
+            p: self.expr.broadcast_producer(shape),
+            f: self.f,
+        }
+    }
+}
+
+impl<E1, E2, F> ZipExpr<E1, E2, F>
+where
+    E1: Expression,
+    E2: Expression,
+    E1::Dim: DimMax<E2::Dim>,
+{
+    fn shape(&self) -> Result<<E1::Dim as DimMax<E2::Dim>>::Output, ShapeError> {
+        co_broadcast(&self.a.raw_dim(), &self.b.raw_dim())
+    }
+}
+
+impl<E1, E2, F, T> Expression for ZipExpr<E1, E2, F>
+where
+    E1: Expression,
+    E2: Expression,
+    E1::Dim: DimMax<E2::Dim>,
+    F: Fn(E1::Elem, E2::Elem) -> T + Clone,
+{
+    type Elem = T;
+    type Dim = <E1::Dim as DimMax<E2::Dim>>::Output;
+
+    fn raw_dim(&self) -> Self::Dim {
+        // the shapes were checked when the expression was created
+        self.shape().unwrap()
+    }
+
+    private_impl! {}
+}
+
+impl<E1, E2, F, T, Sh> BroadcastExpression<Sh> for ZipExpr<E1, E2, F>
+where
+    E1: BroadcastExpression<Sh>,
+    E2: BroadcastExpression<Sh>,
+    E1::Dim: DimMax<E2::Dim>,
+    F: Fn(E1::Elem, E2::Elem) -> T + Clone,
+    Sh: Dimension,
+{
+    type Producer = ZipProducer<E1::Producer, E2::Producer, F>;
+
+    fn broadcast_producer(self, shape: &Sh) -> Self::Producer {
+        ZipProducer {
+            a: self.a.broadcast_producer(shape),
+            b: self.b.broadcast_producer(shape),
+            f: self.f,
+        }
+    }
+}
+
+macro_rules! impl_lazy_binary_op {
+    ($trt:ident, $mth:ident, $doc:expr) => {
+/// Record an elementwise
+#[doc=$doc]
+/// of the two expressions, broadcast to a common shape.
+///
+/// **Panics** if broadcasting isn't possible.
+impl<E1, E2> $trt<Lazy<E2>> for Lazy<E1>
+where
+    E1: Expression,
+    E2: Expression,
+    E1::Dim: DimMax<E2::Dim>,
+    E1::Elem: $trt<E2::Elem>,
+{
+    type Output = Lazy<ZipExpr<E1, E2,
+        fn(E1::Elem, E2::Elem) -> <E1::Elem as $trt<E2::Elem>>::Output>>;
+
+    fn $mth(self, rhs: Lazy<E2>) -> Self::Output {
+        self.zip_with(rhs, $trt::$mth as fn(_, _) -> _)
+    }
+}
+
+/// Record an elementwise
+#[doc=$doc]
+/// of the expression and the scalar `rhs`.
+impl<E, B> $trt<B> for Lazy<E>
+where
+    E: Expression,
+    E::Elem: $trt<B>,
+    B: ScalarOperand,
+{
+    type Output = Lazy<ZipExpr<E, ScalarExpr<B>,
+        fn(E::Elem, B) -> <E::Elem as $trt<B>>::Output>>;
+
+    fn $mth(self, rhs: B) -> Self::Output {
+        self.zip_with(Lazy { expr: ScalarExpr { value: rhs } }, $trt::$mth as fn(_, _) -> _)
+    }
+}
+    };
+}
+
+impl_lazy_binary_op!(Add, add, "addition");
+impl_lazy_binary_op!(Sub, sub, "subtraction");
+impl_lazy_binary_op!(Mul, mul, "multiplication");
+impl_lazy_binary_op!(Div, div, "division");
+impl_lazy_binary_op!(Rem, rem, "remainder");
+impl_lazy_binary_op!(BitAnd, bitand, "bit and");
+impl_lazy_binary_op!(BitOr, bitor, "bit or");
+impl_lazy_binary_op!(BitXor, bitxor, "bit xor");
+impl_lazy_binary_op!(Shl, shl, "left shift");
+impl_lazy_binary_op!(Shr, shr, "right shift");
+
+macro_rules! impl_lazy_scalar_lhs_op {
+    ($scalar:ty, $trt:ident, $mth:ident) => {
+// these have no doc -- they are not visible in rustdoc
+// Record an elementwise operation between the scalar `self` and the expression `rhs`.
+impl<E> $trt<Lazy<E>> for $scalar
+where
+    E: Expression,
+    Ix0: DimMax<E::Dim>,
+    $scalar: $trt<E::Elem>,
+{
+    type Output = Lazy<ZipExpr<ScalarExpr<$scalar>, E,
+        fn($scalar, E::Elem) -> <$scalar as $trt<E::Elem>>::Output>>;
+
+    fn $mth(self, rhs: Lazy<E>) -> Self::Output {
+        Lazy { expr: ScalarExpr { value: self } }.zip_with(rhs, $trt::$mth as fn(_, _) -> _)
+    }
+}
+    };
+}
+
+macro_rules! all_lazy_scalar_ops {
+    ($scalar:ty) => {
+        impl_lazy_scalar_lhs_op!($scalar, Add, add);
+        impl_lazy_scalar_lhs_op!($scalar, Sub, sub);
+        impl_lazy_scalar_lhs_op!($scalar, Mul, mul);
+        impl_lazy_scalar_lhs_op!($scalar, Div, div);
+        impl_lazy_scalar_lhs_op!($scalar, Rem, rem);
+        impl_lazy_scalar_lhs_op!($scalar, BitAnd, bitand);
+        impl_lazy_scalar_lhs_op!($scalar, BitOr, bitor);
+        impl_lazy_scalar_lhs_op!($scalar, BitXor, bitxor);
+        impl_lazy_scalar_lhs_op!($scalar, Shl, shl);
+        impl_lazy_scalar_lhs_op!($scalar, Shr, shr);
+    };
+}
+
+all_lazy_scalar_ops!(i8);
+all_lazy_scalar_ops!(u8);
+all_lazy_scalar_ops!(i16);
+all_lazy_scalar_ops!(u16);
+all_lazy_scalar_ops!(i32);
+all_lazy_scalar_ops!(u32);
+all_lazy_scalar_ops!(i64);
+all_lazy_scalar_ops!(u64);
+all_lazy_scalar_ops!(i128);
+all_lazy_scalar_ops!(u128);
+
+impl_lazy_scalar_lhs_op!(bool, BitAnd, bitand);
+impl_lazy_scalar_lhs_op!(bool, BitOr, bitor);
+impl_lazy_scalar_lhs_op!(bool, BitXor, bitxor);
+
+impl_lazy_scalar_lhs_op!(f32, Add, add);
+impl_lazy_scalar_lhs_op!(f32, Sub, sub);
+impl_lazy_scalar_lhs_op!(f32, Mul, mul);
+impl_lazy_scalar_lhs_op!(f32, Div, div);
+impl_lazy_scalar_lhs_op!(f32, Rem, rem);
+
+impl_lazy_scalar_lhs_op!(f64, Add, add);
+impl_lazy_scalar_lhs_op!(f64, Sub, sub);
+impl_lazy_scalar_lhs_op!(f64, Mul, mul);
+impl_lazy_scalar_lhs_op!(f64, Div, div);
+impl_lazy_scalar_lhs_op!(f64, Rem, rem);
+
+impl_lazy_scalar_lhs_op!(Complex<f32>, Add, add);
+impl_lazy_scalar_lhs_op!(Complex<f32>, Sub, sub);
+impl_lazy_scalar_lhs_op!(Complex<f32>, Mul, mul);
+impl_lazy_scalar_lhs_op!(Complex<f32>, Div, div);
+
+impl_lazy_scalar_lhs_op!(Complex<f64>, Add, add);
+impl_lazy_scalar_lhs_op!(Complex<f64>, Sub, sub);
+impl_lazy_scalar_lhs_op!(Complex<f64>, Mul, mul);
+impl_lazy_scalar_lhs_op!(Complex<f64>, Div, div);
+
+impl<E> Neg for Lazy<E>
+where
+    E: Expression,
+    E::Elem: Neg,
+{
+    type Output = Lazy<MapExpr<E, fn(E::Elem) -> <E::Elem as Neg>::Output>>;
+    /// Record an elementwise negation of the expression.
+    fn neg(self) -> Self::Output {
+        self.map(Neg::neg as fn(_) -> _)
+    }
+}
+
+impl<E> Not for Lazy<E>
+where
+    E: Expression,
+    E::Elem: Not,
+{
+    type Output = Lazy<MapExpr<E, fn(E::Elem) -> <E::Elem as Not>::Output>>;
+    /// Record an elementwise unary not of the expression.
+    fn not(self) -> Self::Output {
+        self.map(Not::not as fn(_) -> _)
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The producers that lazy expressions are evaluated with.
+//!
+//! The types are public so that they can be used in `BroadcastExpression`, but they
+//! are not reachable from outside the crate.
+
+use std::ptr::NonNull;
+
+use crate::imp_prelude::*;
+use crate::split_at::SplitAt;
+use crate::{Layout, NdProducer};
+
+/// Produces clones of the elements of an array view.
+pub struct ClonedProducer<'a, A, D> {
+    pub(super) view: ArrayView<'a, A, D>,
+}
+
+impl<'a, A, D> NdProducer for ClonedProducer<'a, A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    type Item = A;
+    type Dim = D;
+    type Ptr = *mut A;
+    type Stride = isize;
+
+    private_impl! {}
+
+    fn raw_dim(&self) -> Self::Dim {
+        self.view.raw_dim()
+    }
+
+    fn equal_dim(&self, dim: &Self::Dim) -> bool {
+        self.view.dim.equal(dim)
+    }
+
+    fn as_ptr(&self) -> Self::Ptr {
+        self.view.as_ptr() as *mut A
+    }
+
+    fn layout(&self) -> Layout {
+        self.view.layout()
+    }
+
+    unsafe fn as_ref(&self, ptr: Self::Ptr) -> Self::Item {
+        (*ptr).clone()
+    }
+
+    unsafe fn uget_ptr(&self, i: &Self::Dim) -> Self::Ptr {
+        self.view.uget_ptr(i)
+    }
+
+    fn stride_of(&self, axis: Axis) -> Self::Stride {
+        self.view.stride_of(axis)
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride {
+        1
+    }
+
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+        let (a, b) = self.view.split_at(axis, index);
+        (ClonedProducer { view: a }, ClonedProducer { view: b })
+    }
+}
+
+/// Produces clones of a single value, in any shape.
+///
+/// The pointer is a dangling pointer that is never dereferenced, and it is always
+/// offset by zero (all strides are zero).
+pub struct ScalarProducer<B, D> {
+    pub(super) value: B,
+    pub(super) dim: D,
+}
+
+impl<B, D> NdProducer for ScalarProducer<B, D>
+where
+    B: Clone,
+    D: Dimension,
+{
+    type Item = B;
+    type Dim = D;
+    type Ptr = *mut B;
+    type Stride = isize;
+
+    private_impl! {}
+
+    fn raw_dim(&self) -> Self::Dim {
+        self.dim.clone()
+    }
+
+    fn as_ptr(&self) -> Self::Ptr {
+        NonNull::dangling().as_ptr()
+    }
+
+    fn layout(&self) -> Layout {
+        // the same value everywhere is compatible with any memory order
+        Layout::one_dimensional()
+    }
+
+    unsafe fn as_ref(&self, _ptr: Self::Ptr) -> Self::Item {
+        self.value.clone()
+    }
+
+    unsafe fn uget_ptr(&self, _i: &Self::Dim) -> Self::Ptr {
+        self.as_ptr()
+    }
+
+    fn stride_of(&self, _axis: Axis) -> Self::Stride {
+        0
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride {
+        0
+    }
+
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+        let (a, b) = self.dim.split_at(axis, index);
+        (
+            ScalarProducer {
+                value: self.value.clone(),
+                dim: a,
+            },
+            ScalarProducer {
+                value: self.value,
+                dim: b,
+            },
+        )
+    }
+}
+
+/// Produces `f(x)` for each item `x` of the producer `p`.
+pub struct MapProducer<P, F> {
+    pub(super) p: P,
+    pub(super) f: F,
+}
+
+impl<P, F, T> NdProducer for MapProducer<P, F>
+where
+    P: NdProducer,
+    F: Fn(P::Item) -> T + Clone,
+{
+    type Item = T;
+    type Dim = P::Dim;
+    type Ptr = P::Ptr;
+    type Stride = P::Stride;
+
+    private_impl! {}
+
+    fn raw_dim(&self) -> Self::Dim {
+        self.p.raw_dim()
+    }
+
+    fn equal_dim(&self, dim: &Self::Dim) -> bool {
+        self.p.equal_dim(dim)
+    }
+
+    fn as_ptr(&self) -> Self::Ptr {
+        self.p.as_ptr()
+    }
+
+    fn layout(&self) -> Layout {
+        self.p.layout()
+    }
+
+    unsafe fn as_ref(&self, ptr: Self::Ptr) -> Self::Item {
+        (self.f)(self.p.as_ref(ptr))
+    }
+
+    unsafe fn uget_ptr(&self, i: &Self::Dim) -> Self::Ptr {
+        self.p.uget_ptr(i)
+    }
+
+    fn stride_of(&self, axis: Axis) -> Self::Stride {
+        self.p.stride_of(axis)
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride {
+        self.p.contiguous_stride()
+    }
+
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+        let (a, b) = self.p.split_at(axis, index);
+        (
+            MapProducer {
+                p: a,
+                f: self.f.clone(),
+            },
+            MapProducer { p: b, f: self.f },
+        )
+    }
+}
+
+/// Produces `f(x, y)` for each pair of items `x` and `y` of the producers `a` and `b`,
+/// which have the same shape.
+pub struct ZipProducer<P, Q, F> {
+    pub(super) a: P,
+    pub(super) b: Q,
+    pub(super) f: F,
+}
+
+impl<P, Q, F, T> NdProducer for ZipProducer<P, Q, F>
+where
+    P: NdProducer,
+    Q: NdProducer<Dim = P::Dim>,
+    F: Fn(P::Item, Q::Item) -> T + Clone,
+{
+    type Item = T;
+    type Dim = P::Dim;
+    type Ptr = (P::Ptr, Q::Ptr);
+    type Stride = (P::Stride, Q::Stride);
+
+    private_impl! {}
+
+    fn raw_dim(&self) -> Self::Dim {
+        self.a.raw_dim()
+    }
+
+    fn equal_dim(&self, dim: &Self::Dim) -> bool {
+        self.a.equal_dim(dim)
+    }
+
+    fn as_ptr(&self) -> Self::Ptr {
+        (self.a.as_ptr(), self.b.as_ptr())
+    }
+
+    fn layout(&self) -> Layout {
+        self.a.layout().intersect(self.b.layout())
+    }
+
+    unsafe fn as_ref(&self, ptr: Self::Ptr) -> Self::Item {
+        (self.f)(self.a.as_ref(ptr.0), self.b.as_ref(ptr.1))
+    }
+
+    unsafe fn uget_ptr(&self, i: &Self::Dim) -> Self::Ptr {
+        (self.a.uget_ptr(i), self.b.uget_ptr(i))
+    }
+
+    fn stride_of(&self, axis: Axis) -> Self::Stride {
+        (self.a.stride_of(axis), self.b.stride_of(axis))
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride {
+        (self.a.contiguous_stride(), self.b.contiguous_stride())
+    }
+
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+        let (a1, a2) = self.a.split_at(axis, index);
+        let (b1, b2) = self.b.split_at(axis, index);
+        (
+            ZipProducer {
+                a: a1,
+                b: b1,
+                f: self.f.clone(),
+            },
+            ZipProducer {
+                a: a2,
+                b: b2,
+                f: self.f,
+            },
+        )
+    }
+}
@@ -64,6 +64,8 @@
 //!     [`.map_inplace()`](ArrayBase::map_inplace), [`.zip_mut_with()`](ArrayBase::zip_mut_with),
 //!     [`Zip`] and [`azip!()`](azip) are the most efficient ways
 //!     to perform single traversal and lock step traversal respectively.
+//!   + [Lazy expressions](lazy) compute arithmetic with several operands in a single
+//!     traversal, without intermediate arrays.
 //!   + Performance of an operation depends on the memory layout of the array
 //!     or array view. Especially if it's a binary operation, which
 //!     needs matching memory layout to be efficient (with some exceptions).
@@ -87,6 +89,8 @@
 //!   - Enables serialization support for serde 1.x
 //! - `rayon`
 //!   - Enables parallel iterators, parallelized methods, the [`parallel`] module and [`par_azip!`].
+//!   - Without `blas`, large matrix multiplications run in parallel in the current rayon
+//!     thread pool.
 //!   - Implies std
 //! - `approx`
 //!   - Enables implementations of traits from the [`approx`] crate.
@@ -111,8 +115,11 @@
 //!
 //! `ndarray` provides a lot of functionality, but it's not a one-stop solution.
 //!
-//! `ndarray` includes matrix multiplication and other binary/unary operations out of the box.
-//! More advanced linear algebra routines (e.g. SVD decomposition or eigenvalue computation)
+//! `ndarray` includes matrix multiplication and other binary/unary operations out of the box,
+//! and the module [`linalg`] has LU, Cholesky, QR, Hermitian eigenvalue and singular
+//! value decompositions, implemented in Rust, for solving systems of equations and least
+//! squares problems, computing inverses and determinants, and finding eigenvalues.
+//! More advanced linear algebra routines, and bindings to LAPACK,
 //! can be found in [`ndarray-linalg`](https://crates.io/crates/ndarray-linalg).
 //!
 //! The same holds for statistics: `ndarray` provides some basic functionalities (e.g. `mean`)
@@ -173,6 +180,7 @@ mod macro_utils;
 #[macro_use]
 mod private;
 mod aliases;
+mod allocator;
 #[macro_use]
 mod itertools;
 mod argument_traits;
@@ -185,6 +193,7 @@ mod data_repr;
 mod data_traits;
 
 pub use crate::aliases::*;
+pub use crate::allocator::{AllocError, ArrayAllocator, Global};
 
 pub use crate::data_traits::{
     Data, DataMut, DataOwned, DataShared, RawData, RawDataClone, RawDataMut,
@@ -219,7 +228,7 @@ mod zip;
 
 mod dimension;
 
-pub use crate::zip::{FoldWhile, IntoNdProducer, NdProducer, Zip};
+pub use crate::zip::{FnProducer, FoldWhile, IntoNdProducer, NdProducer, Zip};
 
 pub use crate::layout::Layout;
 
@@ -342,6 +351,27 @@ pub type Ixs = isize;
 /// You can also create an array view from a regular slice of data not
 /// allocated with `Array` — see array view methods or their `From` impls.
 ///
+/// Views are also the way to use small fixed size arrays without any heap allocation:
+/// keep the elements in a regular Rust array, for example on the stack, and work
+/// with views of it. (There is no owned array type with inline storage, because an
+/// `ArrayBase` holds a pointer to its elements, which can't point into storage that
+/// moves together with the array value itself.)
+///
+/// ```
+/// use ndarray::{aview2, aview_mut2, Zip};
+///
+/// let a = [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]];
+/// let b = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+/// let mut sum = [[0.; 3]; 3];
+///
+/// Zip::from(aview_mut2(&mut sum))
+///     .and(aview2(&a))
+///     .and(aview2(&b).t())
+///     .for_each(|s, &x, &y| *s = x + y);
+///
+/// assert_eq!(sum, [[2., 2., 3.], [4., 6., 6.], [7., 8., 10.]]);
+/// ```
+///
 /// Note that all `ArrayBase` variants can change their view (slicing) of the
 /// data freely, even when their data can’t be mutated.
 ///
@@ -1343,7 +1373,10 @@ pub type ArcArray<A, D> = ArrayBase<OwnedArcRepr<A>, D>;
 /// [`Array3`](type.Array3.html), ...,
 /// [`ArrayD`](type.ArrayD.html),
 /// and so on.
-pub type Array<A, D> = ArrayBase<OwnedRepr<A>, D>;
+///
+/// The optional parameter `Al` is the allocator of the array's storage, which is the
+/// global allocator by default; see [`ArrayAllocator`](trait.ArrayAllocator.html).
+pub type Array<A, D, Al = Global> = ArrayBase<OwnedRepr<A, Al>, D>;
 
 /// An array with copy-on-write behavior.
 ///
@@ -1536,6 +1569,7 @@ mod impl_constructors;
 
 mod impl_methods;
 mod impl_owned_array;
+mod impl_arc_array;
 mod impl_special_element_types;
 
 /// Private Methods
@@ -1616,6 +1650,8 @@ pub mod linalg;
 mod impl_ops;
 pub use crate::impl_ops::ScalarOperand;
 
+pub mod lazy;
+
 #[cfg(feature = "approx")]
 mod array_approx;
 
@@ -0,0 +1,287 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::min;
+
+use num_traits::{Float, One, Zero};
+
+use crate::imp_prelude::*;
+
+use super::impl_linalg::triangular_solve_mat;
+use super::util::solve_rhs;
+use super::{general_mat_mul, general_mat_vec_mul, LinalgError, LinalgFloat, Triangle};
+
+/// The number of columns in a panel of the blocked factorization.
+const PANEL: usize = 32;
+
+/// Cholesky decomposition of a Hermitian (for real matrices: symmetric) positive
+/// definite matrix.
+///
+/// The decomposition is A = L Lᴴ, where L is lower triangular with a positive real
+/// diagonal, or equivalently A = Uᴴ U with the upper triangular U = Lᴴ. Which of the
+/// two factors is computed is selected with a [`Triangle`], which is also the
+/// triangle of A that is read.
+///
+/// The decomposition can be reused to solve several systems of equations with the
+/// same matrix.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::{Cholesky, Triangle};
+///
+/// let a = arr2(&[[4., 2.],
+///                [2., 5.]]);
+/// let chol = Cholesky::new(&a, Triangle::Lower).unwrap();
+/// assert_eq!(chol.factor(), arr2(&[[2., 0.],
+///                                  [1., 2.]]));
+/// assert_eq!(chol.solve(&arr1(&[10., 13.])).unwrap(), arr1(&[1.5, 2.]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cholesky<A> {
+    /// L or U, with zeros in the other triangle
+    factor: Array2<A>,
+    uplo: Triangle,
+}
+
+impl<A> Cholesky<A>
+where
+    A: LinalgFloat,
+{
+    /// Compute the Cholesky decomposition of the square matrix `a`, reading only its
+    /// triangle `uplo`; the other triangle is assumed to be its conjugate transpose.
+    ///
+    /// **Errors** if `a` is not square or not positive definite.
+    pub fn new<S>(a: &ArrayBase<S, Ix2>, uplo: Triangle) -> Result<Self, LinalgError>
+    where
+        S: Data<Elem = A>,
+    {
+        let (n, m) = a.dim();
+        LinalgError::check_square(n, m)?;
+        // the factorization computes L from the lower triangle
+        let mut factor = match uplo {
+            Triangle::Lower => a.to_owned(),
+            Triangle::Upper => a.t().mapv(A::conj),
+        };
+        factorize(factor.view_mut())?;
+        if uplo == Triangle::Upper {
+            factor = factor.t().mapv(A::conj);
+        }
+        Ok(Cholesky { factor, uplo })
+    }
+
+    /// Return the triangular factor, L or U depending on the triangle that the
+    /// decomposition was computed with.
+    pub fn factor(&self) -> ArrayView2<'_, A> {
+        self.factor.view()
+    }
+
+    /// Return the triangular factor, L or U depending on the triangle that the
+    /// decomposition was computed with.
+    pub fn into_factor(self) -> Array2<A> {
+        self.factor
+    }
+
+    /// Solve the system of equations A x = b for x, where `b` is a vector, or the
+    /// systems A X = B for X, where `b` is a matrix with a right hand side in each
+    /// column.
+    ///
+    /// **Errors** if `b` is not a vector or matrix with as many rows as the matrix.
+    pub fn solve<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        solve_rhs(self.factor.nrows(), b, "solve", |x| {
+            cho_solve_inplace(self.factor.view(), self.uplo, x);
+            Ok(())
+        })
+    }
+
+    /// Return the determinant of the matrix, which is real and positive.
+    pub fn det(&self) -> A::Real {
+        let d = self.factor.diag().iter().fold(A::Real::one(), |acc, x| acc * x.re());
+        d * d
+    }
+}
+
+/// Overwrite the lower triangle of `a` with its Cholesky factor L; the rest of `a`
+/// is overwritten with zeros.
+fn factorize<A>(mut a: ArrayViewMut2<'_, A>) -> Result<(), LinalgError>
+where
+    A: LinalgFloat,
+{
+    let n = a.nrows();
+    for j0 in (0..n).step_by(PANEL) {
+        let j1 = min(j0 + PANEL, n);
+        // compute the columns of L in the panel; the columns left of the panel have
+        // already been subtracted from the rest of the matrix
+        for j in j0..j1 {
+            let l_j = a.slice(s![j, j0..j]);
+            let d = a[[j, j]].re() - l_j.iter().fold(A::Real::zero(), |acc, x| acc + x.abs_sqr());
+            if d.is_nan() || d <= A::Real::zero() {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            let l_jj = d.sqrt();
+            let l_j = l_j.mapv(A::conj);
+            a[[j, j]] = A::from_real(l_jj);
+            let (left, mut col) = a.view_mut().split_at(Axis(1), j);
+            let mut col = col.slice_mut(s![j + 1.., 0]);
+            // a[j+1.., j] ← (a[j+1.., j] − L[j+1.., j0..j] conj(L[j, j0..j])) / l_jj
+            general_mat_vec_mul(
+                -A::one(),
+                &left.slice(s![j + 1.., j0..]),
+                &l_j,
+                A::one(),
+                &mut col,
+            );
+            let l_jj = A::from_real(l_jj);
+            col.map_inplace(|x| *x = *x / l_jj);
+        }
+        if j1 < n {
+            // A22 ← A22 − L21 L21ᴴ
+            let (left, right) = a.view_mut().split_at(Axis(1), j1);
+            let l21 = left.slice(s![j1.., j0..]);
+            general_mat_mul(
+                -A::one(),
+                &l21,
+                &l21.t().mapv(A::conj),
+                A::one(),
+                &mut right.slice_move(s![j1.., ..]),
+            );
+        }
+    }
+    for (i, mut row) in a.rows_mut().into_iter().enumerate() {
+        row.slice_mut(s![i + 1..]).fill(A::zero());
+    }
+    Ok(())
+}
+
+/// Overwrite `b` with the solution X of A X = B, where `factor` is the Cholesky
+/// factor of A in the triangle `uplo`.
+fn cho_solve_inplace<A>(factor: ArrayView2<'_, A>, uplo: Triangle, mut b: ArrayViewMut2<'_, A>)
+where
+    A: LinalgFloat,
+{
+    // A = L Lᴴ; the solve with Lᴴ = conj(L)ᵀ is computed as conj(Lᵀ⁻¹ conj(b)).
+    let (l, l_transposed) = match uplo {
+        Triangle::Lower => (factor, false),
+        Triangle::Upper => (factor.reversed_axes(), true),
+    };
+    let conj = |b: &mut ArrayViewMut2<'_, A>| b.map_inplace(|x| *x = x.conj());
+    if l_transposed {
+        conj(&mut b);
+    }
+    triangular_solve_mat(Triangle::Lower, false, &l, b.view_mut());
+    conj(&mut b);
+    triangular_solve_mat(Triangle::Upper, false, &l.t(), b.view_mut());
+    if !l_transposed {
+        conj(&mut b);
+    }
+}
+
+/// Return the Cholesky factor of the Hermitian (for real matrices: symmetric)
+/// positive definite matrix `a`: L with A = L Lᴴ if `uplo` is `Lower`, or U with
+/// A = Uᴴ U if it is `Upper`.
+///
+/// Only the triangle `uplo` of `a` is read. See [`Cholesky`] for a decomposition that
+/// can solve systems of equations.
+///
+/// **Errors** if `a` is not square or not positive definite.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{cholesky, Triangle};
+///
+/// let a = arr2(&[[4., 2.],
+///                [2., 5.]]);
+/// assert_eq!(cholesky(&a, Triangle::Upper).unwrap(), arr2(&[[2., 1.],
+///                                                           [0., 2.]]));
+/// ```
+pub fn cholesky<A, S>(a: &ArrayBase<S, Ix2>, uplo: Triangle) -> Result<Array2<A>, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    Ok(Cholesky::new(a, uplo)?.into_factor())
+}
+
+/// Solve the system of equations A x = b for x, or A X = B for X, given the Cholesky
+/// factor of A in the triangle `uplo`, as computed by [`cholesky`].
+///
+/// `b` is a vector or a matrix with a right hand side in each column.
+///
+/// **Errors** if `factor` is not square, or if `b` is not a vector or matrix with as
+/// many rows as `factor`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::{cho_solve, cholesky, Triangle};
+///
+/// let a = arr2(&[[4., 2.],
+///                [2., 5.]]);
+/// let l = cholesky(&a, Triangle::Lower).unwrap();
+/// assert_eq!(cho_solve(&l, Triangle::Lower, &arr1(&[10., 13.])).unwrap(), arr1(&[1.5, 2.]));
+/// ```
+pub fn cho_solve<A, S1, S2, D>(
+    factor: &ArrayBase<S1, Ix2>,
+    uplo: Triangle,
+    b: &ArrayBase<S2, D>,
+) -> Result<Array<A, D>, LinalgError>
+where
+    A: LinalgFloat,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    let (n, m) = factor.dim();
+    LinalgError::check_square(n, m)?;
+    solve_rhs(n, b, "cho_solve", |x| {
+        cho_solve_inplace(factor.view(), uplo, x);
+        Ok(())
+    })
+}
+
+/// Solve the triangular system of equations A x = b for x, or A X = B for X, where
+/// only the triangle `uplo` of A is read.
+///
+/// `b` is a vector or a matrix with a right hand side in each column. This is
+/// [`triangular_solve`](super::triangular_solve), but it returns the solution
+/// instead of overwriting `b`, and returns errors instead of panicking.
+///
+/// **Errors** if `a` is not square or has a zero on its diagonal, or if `b` is not
+/// a vector or matrix with as many rows as `a`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::{solve_triangular, Triangle};
+///
+/// let a = arr2(&[[2., 0.],
+///                [1., 4.]]);
+/// assert_eq!(solve_triangular(&a, Triangle::Lower, &arr1(&[2., 9.])).unwrap(), arr1(&[1., 2.]));
+/// ```
+pub fn solve_triangular<A, S1, S2, D>(
+    a: &ArrayBase<S1, Ix2>,
+    uplo: Triangle,
+    b: &ArrayBase<S2, D>,
+) -> Result<Array<A, D>, LinalgError>
+where
+    A: LinalgFloat,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    let (n, m) = a.dim();
+    LinalgError::check_square(n, m)?;
+    solve_rhs(n, b, "solve_triangular", |x| {
+        if a.diag().iter().any(|x| x.is_zero()) {
+            return Err(LinalgError::Singular);
+        }
+        triangular_solve_mat(uplo, false, &a.view(), x);
+        Ok(())
+    })
+}
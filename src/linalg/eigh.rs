@@ -0,0 +1,246 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Ordering;
+
+use alloc::vec::Vec;
+use num_traits::{Float, One, Zero};
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+use super::util::{apply_reflector, make_reflector, reflector};
+use super::{general_outer_add, LinalgError, LinalgFloat, Triangle};
+
+/// The maximum number of QL iterations, on average for each eigenvalue.
+const MAX_ITER: usize = 30;
+
+/// Compute the eigenvalues and eigenvectors of a Hermitian (for real matrices:
+/// symmetric) matrix.
+///
+/// Only the triangle `uplo` of `a` is read; the other triangle is assumed to be its
+/// conjugate transpose, and the imaginary parts of the diagonal are ignored.
+///
+/// Return the eigenvalues in ascending order, and a unitary matrix with the
+/// corresponding eigenvectors in its columns, so that A = V diag(λ) Vᴴ.
+///
+/// The matrix is reduced to a real symmetric tridiagonal matrix with Householder
+/// reflections, whose eigenvalues are computed with the implicit QL algorithm.
+///
+/// **Errors** if `a` is not square, or if the QL iteration does not converge (which
+/// does not happen in practice unless `a` contains NaN or infinity).
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::{eigh, Triangle};
+///
+/// let a = arr2(&[[2., 1.],
+///                [1., 2.]]);
+/// let (w, v) = eigh(&a, Triangle::Lower).unwrap();
+/// assert!((&w - &arr1(&[1., 3.])).iter().all(|x: &f64| x.abs() < 1e-12));
+/// assert!((a.dot(&v) - &v * &w).iter().all(|x: &f64| x.abs() < 1e-12));
+/// ```
+pub fn eigh<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    uplo: Triangle,
+) -> Result<(Array1<A::Real>, Array2<A>), LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (mut d, mut e, q_t) = tridiagonalize(a, uplo, true)?;
+    let mut q_t = q_t.unwrap();
+    tridiagonal_ql(&mut d, &mut e, Some(q_t.view_mut()))?;
+    let order = ascending_order(&d);
+    let values = order.iter().map(|&i| d[i]).collect();
+    Ok((values, q_t.select(Axis(0), &order).reversed_axes()))
+}
+
+/// Compute the eigenvalues of a Hermitian (for real matrices: symmetric) matrix.
+///
+/// Only the triangle `uplo` of `a` is read. Return the eigenvalues in ascending
+/// order; see [`eigh`] for details.
+///
+/// **Errors** if `a` is not square, or if the QL iteration does not converge.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{eigvalsh, Triangle};
+/// use num_complex::Complex64;
+///
+/// let (one, i) = (Complex64::new(1., 0.), Complex64::i());
+/// // the lower triangle is not read
+/// let a = arr2(&[[one, -i],
+///                [one, one]]);
+/// let w = eigvalsh(&a, Triangle::Upper).unwrap();
+/// assert!((w[0] - 0.).abs() < 1e-12 && (w[1] - 2.).abs() < 1e-12);
+/// ```
+pub fn eigvalsh<A, S>(a: &ArrayBase<S, Ix2>, uplo: Triangle) -> Result<Array1<A::Real>, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (mut d, mut e, _) = tridiagonalize(a, uplo, false)?;
+    tridiagonal_ql::<A>(&mut d, &mut e, None)?;
+    d.sort_by(|x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal));
+    Ok(Array1::from(d))
+}
+
+type Tridiagonal<A> = (Vec<<A as LinalgFloat>::Real>, Vec<<A as LinalgFloat>::Real>, Option<Array2<A>>);
+
+/// Reduce the Hermitian matrix in the triangle `uplo` of `a` to a real symmetric
+/// tridiagonal matrix T = Qᴴ A Q.
+///
+/// Return the diagonal of T, its subdiagonal (with a zero appended), and if
+/// `with_q`, the transpose of Q.
+fn tridiagonalize<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    uplo: Triangle,
+    with_q: bool,
+) -> Result<Tridiagonal<A>, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (n, m) = a.dim();
+    LinalgError::check_square(n, m)?;
+    let mut a = Array2::from_shape_fn((n, n), |(i, j)| match (i.cmp(&j), uplo) {
+        (Ordering::Equal, _) => A::from_real(a[[i, i]].re()),
+        (Ordering::Greater, Triangle::Lower) | (Ordering::Less, Triangle::Upper) => a[[i, j]],
+        _ => a[[j, i]].conj(),
+    });
+    let mut d = Vec::with_capacity(n);
+    let mut e = Vec::with_capacity(n);
+    let mut tau = Vec::with_capacity(n);
+    for k in 0..n {
+        d.push(a[[k, k]].re());
+        if k + 1 == n {
+            e.push(A::Real::zero());
+            break;
+        }
+        // the reflection H with Hᴴ x = β e₁ for the part of column k below the
+        // diagonal, which is stored there, and A₂₂ ← Hᴴ A₂₂ H
+        let (left, mut a22) = a.view_mut().split_at(Axis(1), k + 1);
+        let mut x = left.index_axis_move(Axis(1), k).slice_move(s![k + 1..]);
+        let tau_k = make_reflector(x.view_mut());
+        e.push(x[0].re());
+        tau.push(tau_k);
+        if tau_k.is_zero() {
+            continue;
+        }
+        let v = reflector(x.view(), 0);
+        let mut a22 = a22.slice_mut(s![k + 1.., ..]);
+        // with p = τ A₂₂ v and w = p − ½ τ̅ (vᴴ p) v:
+        // Hᴴ A₂₂ H = A₂₂ − w vᴴ − v wᴴ
+        let p = a22.dot(&v) * tau_k;
+        let half = A::from_real(A::Real::one() / (A::Real::one() + A::Real::one()));
+        let vp = v.iter().zip(&p).fold(A::zero(), |acc, (&v, &p)| acc + v.conj() * p);
+        let w = p - &(&v * (tau_k.conj() * half * vp));
+        general_outer_add(-A::one(), &w, &v.mapv(A::conj), &mut a22);
+        general_outer_add(-A::one(), &v, &w.mapv(A::conj), &mut a22);
+    }
+    let q_t = if with_q {
+        // Q = H₀ H₁ … applied to the identity matrix
+        let mut q_t = Array2::eye(n);
+        for (k, &tau) in tau.iter().enumerate().rev() {
+            let v = reflector(a.column(k), k + 1);
+            apply_reflector(&v, tau, q_t.slice_mut(s![k + 1.., k + 1..]));
+        }
+        Some(q_t)
+    } else {
+        None
+    };
+    Ok((d, e, q_t))
+}
+
+/// Compute the eigenvalues of the real symmetric tridiagonal matrix with diagonal
+/// `d` and subdiagonal `e` (whose last element is not used) with the implicit QL
+/// algorithm, and overwrite `d` with them.
+///
+/// The rotations are applied to the rows of `z_t`, if present, so that each row of
+/// the transpose of Q becomes the eigenvector of Q T Qᴴ for the eigenvalue in `d`.
+fn tridiagonal_ql<A>(
+    d: &mut [A::Real],
+    e: &mut [A::Real],
+    mut z_t: Option<ArrayViewMut2<'_, A>>,
+) -> Result<(), LinalgError>
+where
+    A: LinalgFloat,
+{
+    let n = d.len();
+    let two = A::Real::one() + A::Real::one();
+    let mut iter = 0;
+    for l in 0..n {
+        loop {
+            // find a negligible subdiagonal element, splitting the matrix
+            let mut m = l;
+            while m + 1 < n {
+                let dd = Float::abs(d[m]) + Float::abs(d[m + 1]);
+                if Float::abs(e[m]) <= A::Real::epsilon() * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            iter += 1;
+            if iter > MAX_ITER * n {
+                return Err(LinalgError::NoConvergence);
+            }
+            // Wilkinson shift from the leading 2 × 2 block
+            let g = (d[l + 1] - d[l]) / (two * e[l]);
+            let r = g.hypot(A::Real::one());
+            let mut g = d[m] - d[l] + e[l] / (g + r.copysign(g));
+            let (mut s, mut c, mut p) = (A::Real::one(), A::Real::one(), A::Real::zero());
+            let mut underflow = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                let r = f.hypot(g);
+                e[i + 1] = r;
+                if r.is_zero() {
+                    // recover from underflow
+                    d[i + 1] -= p;
+                    e[m] = A::Real::zero();
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                let r = (d[i] - g) * s + two * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                if let Some(z_t) = &mut z_t {
+                    let (mut z_i, mut z_next) = z_t.multi_slice_mut((s![i, ..], s![i + 1, ..]));
+                    let (s, c) = (A::from_real(s), A::from_real(c));
+                    Zip::from(&mut z_i).and(&mut z_next).for_each(|zi, zn| {
+                        let f = *zn;
+                        *zn = s * *zi + c * f;
+                        *zi = c * *zi - s * f;
+                    });
+                }
+            }
+            if !underflow {
+                d[l] -= p;
+                e[l] = g;
+                e[m] = A::Real::zero();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Return the indices that sort `x` in ascending order.
+fn ascending_order<F: Float>(x: &[F]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..x.len()).collect();
+    order.sort_by(|&i, &j| x[i].partial_cmp(&x[j]).unwrap_or(Ordering::Equal));
+    order
+}
@@ -0,0 +1,73 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::ShapeError;
+
+/// An error from a matrix decomposition or from an operation that uses one.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinalgError {
+    /// The operation needs a square matrix, but the matrix is `rows` × `cols`.
+    NotSquare {
+        /// The number of rows of the matrix
+        rows: usize,
+        /// The number of columns of the matrix
+        cols: usize,
+    },
+    /// The matrix is singular, so the operation has no unique result.
+    Singular,
+    /// The matrix is not positive definite, so it has no Cholesky decomposition.
+    NotPositiveDefinite,
+    /// An iterative algorithm did not converge.
+    NoConvergence,
+    /// The shapes of the operands are not compatible.
+    Shape(ShapeError),
+}
+
+impl LinalgError {
+    /// Return an error unless a `rows` × `cols` matrix is square.
+    pub(crate) fn check_square(rows: usize, cols: usize) -> Result<(), LinalgError> {
+        if rows == cols {
+            Ok(())
+        } else {
+            Err(LinalgError::NotSquare { rows, cols })
+        }
+    }
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::NotSquare { rows, cols } => {
+                write!(f, "the matrix is not square ({} × {})", rows, cols)
+            }
+            LinalgError::Singular => write!(f, "the matrix is singular"),
+            LinalgError::NotPositiveDefinite => write!(f, "the matrix is not positive definite"),
+            LinalgError::NoConvergence => write!(f, "the algorithm did not converge"),
+            LinalgError::Shape(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for LinalgError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LinalgError::Shape(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ShapeError> for LinalgError {
+    fn from(e: ShapeError) -> Self {
+        LinalgError::Shape(e)
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{Float, NumCast, Zero};
+
+use crate::imp_prelude::*;
+
+use super::{inv, solve, LinalgError, LinalgFloat};
+
+/// Coefficients of the numerators of the [m/m] Padé approximants of the exponential
+/// function, for m = 3, 5, 7, 9 and 13.
+const PADE_3: [f64; 4] = [120., 60., 12., 1.];
+const PADE_5: [f64; 6] = [30240., 15120., 3360., 420., 30., 1.];
+const PADE_7: [f64; 8] = [17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.];
+const PADE_9: [f64; 10] = [
+    17643225600.,
+    8821612800.,
+    2075673600.,
+    302702400.,
+    30270240.,
+    2162160.,
+    110880.,
+    3960.,
+    90.,
+    1.,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+
+/// The largest 1-norms of a matrix for which the Padé approximants of degrees 3, 5, 7,
+/// 9 and 13 are accurate in double precision (Higham 2005, table 2.3).
+const THETA_F64: [f64; 5] = [
+    1.495585217958292e-2,
+    2.53939833006323e-1,
+    9.504178996162932e-1,
+    2.097847961257068,
+    5.371920351148152,
+];
+/// The same for degrees 3, 5 and 7 in single precision.
+const THETA_F32: [f64; 3] = [4.258730016922831e-1, 1.880152677804762, 3.92572478313866];
+
+/// The largest norm for an approximant, and its coefficients.
+type Approximant = (f64, &'static [f64]);
+
+/// Return the square matrix `a` raised to the integer power `n`.
+///
+/// The power is computed by repeated squaring, and a negative power is a power of
+/// the inverse of `a`. The zeroth power is the identity matrix.
+///
+/// **Errors** if `a` is not square, or if `n` is negative and `a` is singular.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::matrix_power;
+///
+/// // Fibonacci numbers
+/// let a = arr2(&[[1., 1.],
+///                [1., 0.]]);
+/// assert_eq!(matrix_power(&a, 10).unwrap(), arr2(&[[89., 55.],
+///                                                 [55., 34.]]));
+/// assert_eq!(matrix_power(&a, -1).unwrap(), arr2(&[[0., 1.],
+///                                                 [1., -1.]]));
+/// ```
+pub fn matrix_power<A, S>(a: &ArrayBase<S, Ix2>, n: i32) -> Result<Array2<A>, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (rows, cols) = a.dim();
+    LinalgError::check_square(rows, cols)?;
+    let mut base = if n < 0 { inv(a)? } else { a.to_owned() };
+    let mut exp = (n as i64).abs();
+    let mut result: Option<Array2<A>> = None;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = Some(match result {
+                Some(result) => result.dot(&base),
+                None => base.clone(),
+            });
+        }
+        exp /= 2;
+        if exp > 0 {
+            base = base.dot(&base);
+        }
+    }
+    Ok(result.unwrap_or_else(|| Array2::eye(rows)))
+}
+
+/// Return the matrix exponential of the square matrix `a`.
+///
+/// The exponential is computed with the scaling and squaring algorithm: it is
+/// approximated with a Padé approximant of `a`, or of `a` / 2ˢ for a large matrix,
+/// which is then squared *s* times. The degree of the approximant and *s* are chosen
+/// by the 1-norm of `a` for an error at the level of the machine precision
+/// (N. J. Higham, *The scaling and squaring method for the matrix exponential
+/// revisited*, 2005).
+///
+/// The result is all NaN if `a` contains NaN or infinity.
+///
+/// **Errors** if `a` is not square.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::expm;
+///
+/// // the exponential of a nilpotent matrix is a finite series
+/// let a = arr2(&[[0., 1., 2.],
+///                [0., 0., 3.],
+///                [0., 0., 0.]]);
+/// let expected = arr2(&[[1., 1., 3.5],
+///                       [0., 1., 3.],
+///                       [0., 0., 1.]]);
+/// assert!((expm(&a).unwrap() - expected).iter().all(|x: &f64| x.abs() < 1e-14));
+/// ```
+pub fn expm<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array2<A>, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (rows, cols) = a.dim();
+    LinalgError::check_square(rows, cols)?;
+    let real = |x: f64| A::from_real(<A::Real as NumCast>::from(x).unwrap());
+    let norm = one_norm(a);
+    if !norm.is_finite() {
+        return Ok(Array2::from_elem((rows, cols), A::from_real(A::Real::nan())));
+    }
+    // the approximants that are used without scaling, and the one that is used with
+    let double = A::Real::epsilon() < <A::Real as NumCast>::from(1e-10).unwrap();
+    let (unscaled, (theta_max, b)): (&[Approximant], Approximant) = if double {
+        (
+            &[
+                (THETA_F64[0], &PADE_3),
+                (THETA_F64[1], &PADE_5),
+                (THETA_F64[2], &PADE_7),
+                (THETA_F64[3], &PADE_9),
+            ],
+            (THETA_F64[4], &PADE_13),
+        )
+    } else {
+        (
+            &[(THETA_F32[0], &PADE_3), (THETA_F32[1], &PADE_5)],
+            (THETA_F32[2], &PADE_7),
+        )
+    };
+    for &(theta, b) in unscaled {
+        if norm <= <A::Real as NumCast>::from(theta).unwrap() {
+            return pade(&a.to_owned(), b, real);
+        }
+    }
+
+    // scale so that the norm is at most the largest theta
+    let ratio: f64 = NumCast::from(norm / <A::Real as NumCast>::from(theta_max).unwrap()).unwrap();
+    let squarings = if ratio > 1. { ratio.log2().ceil() as i32 } else { 0 };
+    let a = a.mapv(|x| x * real(2f64.powi(-squarings)));
+    let mut result = pade(&a, b, real)?;
+    for _ in 0..squarings {
+        result = result.dot(&result);
+    }
+    Ok(result)
+}
+
+/// Return the [m/m] Padé approximant, with numerator coefficients `b`, of the
+/// exponential of `a`.
+fn pade<A, F>(a: &Array2<A>, b: &[f64], real: F) -> Result<Array2<A>, LinalgError>
+where
+    A: LinalgFloat,
+    F: Fn(f64) -> A,
+{
+    let n = a.nrows();
+    let b: Vec<A> = b.iter().map(|&b| real(b)).collect();
+    let eye = Array2::<A>::eye(n);
+    let a2 = a.dot(a);
+    // the odd and even parts of the numerator: p(A) = U + V and q(A) = p(−A) = V − U
+    let (u, v) = if b.len() == 14 {
+        // evaluate the polynomials of degree 13 with fewer matrix products
+        let a4 = a2.dot(&a2);
+        let a6 = a4.dot(&a2);
+        let u = a6.dot(&(&a6 * b[13] + &a4 * b[11] + &a2 * b[9]))
+            + &a6 * b[7]
+            + &a4 * b[5]
+            + &a2 * b[3]
+            + &eye * b[1];
+        let v = a6.dot(&(&a6 * b[12] + &a4 * b[10] + &a2 * b[8]))
+            + &a6 * b[6]
+            + &a4 * b[4]
+            + &a2 * b[2]
+            + &eye * b[0];
+        (a.dot(&u), v)
+    } else {
+        let mut u = Array2::zeros((n, n));
+        let mut v = Array2::zeros((n, n));
+        let mut power = eye;
+        for pair in b.chunks(2) {
+            v.scaled_add(pair[0], &power);
+            u.scaled_add(pair[1], &power);
+            power = power.dot(&a2);
+        }
+        (a.dot(&u), v)
+    };
+    solve(&(&v - &u), &(v + u))
+}
+
+/// Return the 1-norm of `a`: the largest sum of absolute values of a column.
+fn one_norm<A, S>(a: &ArrayBase<S, Ix2>) -> A::Real
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    a.columns()
+        .into_iter()
+        .map(|col| col.iter().fold(A::Real::zero(), |acc, x| acc + x.abs()))
+        .fold(A::Real::zero(), A::Real::max)
+}
@@ -0,0 +1,181 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Cache blocked matrix multiplication for any `LinalgScalar`.
+//!
+//! This is the same scheme as in matrixmultiply (and BLIS), but with a portable
+//! kernel: the operands are copied, block by block, into packed buffers that are read
+//! sequentially by a kernel that computes an `MR` × `NR` block of the result in a
+//! local buffer. The block is small enough to be kept in vector registers, and the
+//! kernel's innermost loop runs along a row of `NR` elements, which the compiler can
+//! vectorize for the primitive types.
+//!
+//! On x86, the kernel is compiled for AVX2 and SSE4.1 too, and the version to use is
+//! picked at runtime; the baseline SSE2 has no vector multiplication of 32-bit integers.
+
+use alloc::vec;
+use std::cmp::min;
+
+use crate::imp_prelude::*;
+use crate::LinalgScalar;
+
+/// Rows of the kernel's block of the result
+const MR: usize = 6;
+/// Columns of the kernel's block of the result
+const NR: usize = 16;
+/// Rows of the packed blocks of A
+const MC: usize = 72;
+/// Depth of the packed blocks of A and B
+const KC: usize = 256;
+/// Columns of the packed blocks of B
+const NC: usize = 1024;
+
+/// C ← α A B + β C
+///
+/// `c` may be uninitialized if `beta` is zero.
+pub(crate) fn gemm_blocked<A>(
+    alpha: A,
+    lhs: &ArrayView2<'_, A>,
+    rhs: &ArrayView2<'_, A>,
+    beta: A,
+    c: &mut ArrayViewMut2<'_, A>,
+) where
+    A: LinalgScalar,
+{
+    let ((m, k), (_, n)) = (lhs.dim(), rhs.dim());
+    if m == 0 || n == 0 {
+        return;
+    }
+    if beta.is_zero() {
+        c.fill(A::zero());
+    } else {
+        c.map_inplace(|x| *x = *x * beta);
+    }
+    if k == 0 {
+        return;
+    }
+
+    // packed blocks; padded with zeros to whole kernel blocks
+    let mut apack = vec![A::zero(); min(MC, round_up(m, MR)) * min(KC, k)];
+    let mut bpack = vec![A::zero(); min(NC, round_up(n, NR)) * min(KC, k)];
+
+    for jc in (0..n).step_by(NC) {
+        let nc = min(NC, n - jc);
+        for pc in (0..k).step_by(KC) {
+            let kc = min(KC, k - pc);
+            let b = rhs.slice(s![pc..pc + kc, jc..jc + nc]);
+            pack(&mut bpack, b.t(), NR);
+            for ic in (0..m).step_by(MC) {
+                let mc = min(MC, m - ic);
+                let a = lhs.slice(s![ic..ic + mc, pc..pc + kc]);
+                pack(&mut apack, a, MR);
+                let c = c.slice_mut(s![ic..ic + mc, jc..jc + nc]);
+                macro_kernel(alpha, kc, &apack, &bpack, c);
+            }
+        }
+    }
+}
+
+/// Add α times the product of the packed blocks of A and B to `c`, using the
+/// widest vector instructions that the CPU supports.
+fn macro_kernel<A: LinalgScalar>(alpha: A, kc: usize, apack: &[A], bpack: &[A],
+                                 c: ArrayViewMut2<'_, A>)
+{
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { macro_kernel_avx2(alpha, kc, apack, bpack, c) };
+        } else if is_x86_feature_detected!("sse4.1") {
+            return unsafe { macro_kernel_sse41(alpha, kc, apack, bpack, c) };
+        }
+    }
+    macro_kernel_impl(alpha, kc, apack, bpack, c)
+}
+
+#[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature(enable = "avx2")]
+unsafe fn macro_kernel_avx2<A: LinalgScalar>(alpha: A, kc: usize, apack: &[A], bpack: &[A],
+                                             c: ArrayViewMut2<'_, A>)
+{
+    macro_kernel_impl(alpha, kc, apack, bpack, c)
+}
+
+#[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn macro_kernel_sse41<A: LinalgScalar>(alpha: A, kc: usize, apack: &[A], bpack: &[A],
+                                              c: ArrayViewMut2<'_, A>)
+{
+    macro_kernel_impl(alpha, kc, apack, bpack, c)
+}
+
+#[inline(always)]
+fn macro_kernel_impl<A: LinalgScalar>(alpha: A, kc: usize, apack: &[A], bpack: &[A],
+                                      mut c: ArrayViewMut2<'_, A>)
+{
+    let (mc, nc) = c.dim();
+    for (jr, bpanel) in bpack.chunks(kc * NR).take(div_ceil(nc, NR)).enumerate() {
+        for (ir, apanel) in apack.chunks(kc * MR).take(div_ceil(mc, MR)).enumerate() {
+            let acc = kernel(kc, apanel, bpanel);
+            let (i0, j0) = (ir * MR, jr * NR);
+            for (i, row) in acc.iter().enumerate().take(mc - i0) {
+                for (j, &x) in row.iter().enumerate().take(nc - j0) {
+                    let elt = &mut c[[i0 + i, j0 + j]];
+                    *elt = *elt + alpha * x;
+                }
+            }
+        }
+    }
+}
+
+/// Compute the product of a packed panel of `MR` rows of A and a packed panel of
+/// `NR` columns of B, both of depth `kc`.
+///
+/// The `MR` × `NR` block of the result is small enough to be kept in vector registers.
+#[inline(always)]
+fn kernel<A: LinalgScalar>(kc: usize, apanel: &[A], bpanel: &[A]) -> [[A; NR]; MR] {
+    let mut acc = [[A::zero(); NR]; MR];
+    let apanel = &apanel[..kc * MR];
+    let bpanel = &bpanel[..kc * NR];
+    for p in 0..kc {
+        let a = &apanel[p * MR..p * MR + MR];
+        let mut b = [A::zero(); NR];
+        b.copy_from_slice(&bpanel[p * NR..p * NR + NR]);
+        for i in 0..MR {
+            for j in 0..NR {
+                acc[i][j] = acc[i][j] + a[i] * b[j];
+            }
+        }
+    }
+    acc
+}
+
+/// Copy the rows of `a` into `buf` in panels of `width` rows; each panel is stored
+/// column by column, and the last panel is padded with zeros.
+fn pack<A: LinalgScalar>(buf: &mut [A], a: ArrayView2<'_, A>, width: usize) {
+    let (rows, cols) = a.dim();
+    for (panel, rows) in buf
+        .chunks_mut(cols * width)
+        .zip(a.axis_chunks_iter(Axis(0), width))
+    {
+        for (dst, col) in panel.chunks_mut(width).zip(rows.columns()) {
+            for (i, d) in dst.iter_mut().enumerate() {
+                *d = if i < col.len() { col[i] } else { A::zero() };
+            }
+        }
+    }
+    debug_assert!(buf.len() >= round_up(rows, width) * cols);
+}
+
+fn div_ceil(x: usize, d: usize) -> usize {
+    let (whole, rem) = (x / d, x % d);
+    whole + (rem > 0) as usize
+}
+
+fn round_up(x: usize, d: usize) -> usize {
+    div_ceil(x, d) * d
+}
@@ -6,14 +6,25 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::error::{from_kind, ErrorKind};
 use crate::imp_prelude::*;
 use crate::numeric_util;
+use crate::{arr0, ShapeError};
 
 use crate::{LinalgScalar, Zip};
 
+use super::gemm_blocked::gemm_blocked;
+#[cfg(feature = "std")]
+use super::LinalgFloat;
+
+use matrixmultiply::CGemmOption;
+
 use std::any::TypeId;
+use std::ops::Range;
 use alloc::vec::Vec;
 
+use num_complex::{Complex, Complex32, Complex64};
+
 #[cfg(feature = "blas")]
 use std::cmp;
 #[cfg(feature = "blas")]
@@ -24,7 +35,10 @@ use libc::c_int;
 #[cfg(feature = "blas")]
 use cblas_sys as blas_sys;
 #[cfg(feature = "blas")]
-use cblas_sys::{CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_LAYOUT};
+use cblas_sys::{
+    CblasConjTrans, CblasLeft, CblasNoTrans, CblasNonUnit, CblasRowMajor, CblasTrans, CBLAS_LAYOUT,
+    CBLAS_DIAG, CBLAS_TRANSPOSE, CBLAS_UPLO,
+};
 
 /// len of vector before we use blas
 #[cfg(feature = "blas")]
@@ -33,8 +47,18 @@ const DOT_BLAS_CUTOFF: usize = 32;
 #[cfg(feature = "blas")]
 const GEMM_BLAS_CUTOFF: usize = 7;
 #[cfg(feature = "blas")]
+const AXPY_BLAS_CUTOFF: usize = 32;
+#[cfg(feature = "blas")]
 #[allow(non_camel_case_types)]
 type blas_index = c_int; // blas index type
+/// size of matrix product (m × k × n) before we use the blocked generic kernel
+const GEMM_BLOCKED_CUTOFF: usize = 32 * 32 * 32;
+/// size of matrix product (m × k × n) before we use multiple threads
+#[cfg(all(feature = "rayon", not(feature = "blas")))]
+const GEMM_PAR_CUTOFF: usize = 128 * 128 * 128;
+/// side of output tiles that are not split further
+#[cfg(all(feature = "rayon", not(feature = "blas")))]
+const GEMM_PAR_MIN_TILE: usize = 64;
 
 impl<A, S> ArrayBase<S, Ix1>
 where
@@ -55,8 +79,8 @@ where
     /// shape *N*.
     ///
     /// **Panics** if the array shapes are incompatible.<br>
-    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64` when memory
-    /// layout allows.
+    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64`, `Complex<f32>`
+    /// and `Complex<f64>` when memory layout allows.
     pub fn dot<Rhs>(&self, rhs: &Rhs) -> <Self as Dot<Rhs>>::Output
     where
         Self: Dot<Rhs>,
@@ -127,6 +151,33 @@ where
 
             dot! {f32, cblas_sdot};
             dot! {f64, cblas_ddot};
+
+            // the complex dot products return the result through a pointer
+            macro_rules! dotu {
+                ($ty:ty, $func:ident) => {{
+                    if blas_compat_1d::<$ty, _>(self) && blas_compat_1d::<$ty, _>(rhs) {
+                        unsafe {
+                            let (lhs_ptr, n, incx) =
+                                blas_1d_params(self.ptr.as_ptr(), self.len(), self.strides()[0]);
+                            let (rhs_ptr, _, incy) =
+                                blas_1d_params(rhs.ptr.as_ptr(), rhs.len(), rhs.strides()[0]);
+                            let mut ret = <$ty>::new(0., 0.);
+                            blas_sys::$func(
+                                n,
+                                lhs_ptr as *const _,
+                                incx,
+                                rhs_ptr as *const _,
+                                incy,
+                                &mut ret as *mut $ty as *mut _,
+                            );
+                            return cast_as::<$ty, A>(&ret);
+                        }
+                    }
+                }};
+            }
+
+            dotu! {Complex32, cblas_cdotu_sub};
+            dotu! {Complex64, cblas_zdotu_sub};
         }
         self.dot_generic(rhs)
     }
@@ -181,8 +232,8 @@ where
     /// of complex operands, and thus not their inner product).
     ///
     /// **Panics** if the arrays are not of the same length.<br>
-    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64` when memory
-    /// layout allows.
+    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64`, `Complex<f32>`
+    /// and `Complex<f64>` when memory layout allows.
     fn dot(&self, rhs: &ArrayBase<S2, Ix1>) -> A {
         self.dot_impl(rhs)
     }
@@ -226,9 +277,12 @@ where
     /// **Panics** if shapes are incompatible or the number of elements in the
     /// result would overflow `isize`.
     ///
-    /// *Note:* If enabled, uses blas `gemv/gemm` for elements of `f32, f64`
-    /// when memory layout allows. The default matrixmultiply backend
-    /// is otherwise used for `f32, f64` for all memory layouts.
+    /// *Note:* If enabled, uses blas `gemv/gemm` for elements of `f32, f64`,
+    /// `Complex<f32>` and `Complex<f64>` when memory layout allows. The default
+    /// matrixmultiply backend is otherwise used for these element types for all
+    /// memory layouts. Large matrix products of other element types use a cache
+    /// blocked kernel; see [`general_mat_mul`]
+    /// for how they are computed.
     ///
     /// ```
     /// use ndarray::arr2;
@@ -281,6 +335,102 @@ where
     }
 }
 
+impl<A, S> ArrayBase<S, IxDyn>
+where
+    S: Data<Elem = A>,
+{
+    /// Perform dot product or matrix multiplication of arrays `self` and `rhs`,
+    /// which have a dynamic number of dimensions.
+    ///
+    /// The kind of product is selected at runtime from the number of dimensions of
+    /// the operands, which must be one or two each: it is the dot product of two
+    /// vectors (returned as a zero-dimensional array), the product of a matrix and
+    /// a column vector, of a row vector and a matrix, or of two matrices. The rules
+    /// for their shapes are the same as for the corresponding products of arrays
+    /// with static dimensions.
+    ///
+    /// **Errors** if an operand has some other number of dimensions, if the array
+    /// shapes are incompatible, or if the number of elements in the result would
+    /// overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::{arr0, arr1, arr2};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [0., 1.]]).into_dyn();
+    /// let x = arr1(&[1., 2.]).into_dyn();
+    ///
+    /// assert_eq!(a.dot(&x).unwrap(), arr1(&[5., 2.]).into_dyn());
+    /// assert_eq!(x.dot(&a).unwrap(), arr1(&[1., 4.]).into_dyn());
+    /// assert_eq!(x.dot(&x).unwrap(), arr0(5.).into_dyn());
+    /// assert!(a.dot(&arr1(&[1., 2., 3.]).into_dyn()).is_err());
+    /// ```
+    pub fn dot<Rhs>(&self, rhs: &Rhs) -> <Self as Dot<Rhs>>::Output
+    where
+        Self: Dot<Rhs>,
+    {
+        Dot::dot(self, rhs)
+    }
+}
+
+impl<A, S, S2> Dot<ArrayBase<S2, IxDyn>> for ArrayBase<S, IxDyn>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    type Output = Result<ArrayD<A>, ShapeError>;
+
+    /// Perform the product of `self` and `rhs` that fits their number of
+    /// dimensions.
+    ///
+    /// **Errors** if the number of dimensions or the shapes are incompatible.
+    fn dot(&self, rhs: &ArrayBase<S2, IxDyn>) -> Self::Output {
+        let error = |kind| from_kind(kind).with_operation("dot");
+        let (m, k) = match *self.shape() {
+            [k] => (1, k),
+            [m, k] => (m, k),
+            _ => return Err(error(ErrorKind::IncompatibleShape).with_index(0)),
+        };
+        let (k2, n) = match *rhs.shape() {
+            [k2] => (k2, 1),
+            [k2, n] => (k2, n),
+            _ => return Err(error(ErrorKind::IncompatibleShape).with_index(1)),
+        };
+        if k != k2 {
+            let mut expected = rhs.shape().to_vec();
+            expected[0] = k;
+            return Err(error(ErrorKind::IncompatibleShape)
+                .with_shapes(&expected, rhs.shape())
+                .with_index(1));
+        }
+        match m.checked_mul(n) {
+            Some(len) if len <= isize::MAX as usize => {}
+            _ => return Err(error(ErrorKind::Overflow)),
+        }
+        let lhs = self.view();
+        let rhs = rhs.view();
+        Ok(match (lhs.ndim(), rhs.ndim()) {
+            (1, 1) => {
+                let dot = lhs.into_dimensionality::<Ix1>()?.dot(&rhs.into_dimensionality::<Ix1>()?);
+                arr0(dot).into_dyn()
+            }
+            (1, _) => lhs
+                .into_dimensionality::<Ix1>()?
+                .dot(&rhs.into_dimensionality::<Ix2>()?)
+                .into_dyn(),
+            (_, 1) => lhs
+                .into_dimensionality::<Ix2>()?
+                .dot(&rhs.into_dimensionality::<Ix1>()?)
+                .into_dyn(),
+            _ => lhs
+                .into_dimensionality::<Ix2>()?
+                .dot(&rhs.into_dimensionality::<Ix2>()?)
+                .into_dyn(),
+        })
+    }
+}
+
 /// Assumes that `m` and `n` are ≤ `isize::MAX`.
 #[cold]
 #[inline(never)]
@@ -344,7 +494,10 @@ where
     ///
     /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
     ///
-    /// **Panics** if broadcasting isn’t possible.
+    /// **Panics** if broadcasting isn’t possible.<br>
+    /// *Note:* If enabled, uses blas `axpy` for elements of `f32, f64`,
+    /// `Complex<f32>` and `Complex<f64>` when both arrays are contiguous and have
+    /// the same shape and memory layout.
     pub fn scaled_add<S2, E>(&mut self, alpha: A, rhs: &ArrayBase<S2, E>)
     where
         S: DataMut,
@@ -352,15 +505,116 @@ where
         A: LinalgScalar,
         E: Dimension,
     {
+        #[cfg(feature = "blas")]
+        {
+            let mut y = self.view_mut();
+            if y.len() >= AXPY_BLAS_CUTOFF
+                && y.len() <= blas_index::MAX as usize
+                && y.shape() == rhs.shape()
+                && y.strides() == rhs.strides()
+            {
+                if let Some(x) = rhs.as_slice_memory_order() {
+                    let y = y.as_slice_memory_order_mut().unwrap();
+                    macro_rules! axpy {
+                        ($ty:ty, $axpy:ident, |$s:ident| $scalar:expr) => {
+                            if same_type::<A, $ty>() {
+                                unsafe {
+                                    blas_sys::$axpy(
+                                        x.len() as blas_index,
+                                        { let $s = &alpha; $scalar },
+                                        x.as_ptr() as *const _,
+                                        1,
+                                        y.as_mut_ptr() as *mut _,
+                                        1,
+                                    );
+                                }
+                                return;
+                            }
+                        };
+                    }
+                    axpy!(f32, cblas_saxpy, |s| cast_as(s));
+                    axpy!(f64, cblas_daxpy, |s| cast_as(s));
+                    axpy!(Complex32, cblas_caxpy, |s| s as *const A as *const _);
+                    axpy!(Complex64, cblas_zaxpy, |s| s as *const A as *const _);
+                }
+            }
+        }
         self.zip_mut_with(rhs, move |y, &x| *y = *y + (alpha * x));
     }
 }
 
 // mat_mul_impl uses ArrayView arguments to send all array kinds into
 // the same instantiated implementation.
-#[cfg(not(feature = "blas"))]
+#[cfg(all(not(feature = "blas"), not(feature = "rayon")))]
 use self::mat_mul_general as mat_mul_impl;
 
+#[cfg(all(not(feature = "blas"), feature = "rayon"))]
+fn mat_mul_impl<A>(
+    alpha: A,
+    lhs: &ArrayView2<'_, A>,
+    rhs: &ArrayView2<'_, A>,
+    beta: A,
+    c: &mut ArrayViewMut2<'_, A>,
+) where
+    A: LinalgScalar,
+{
+    let ((m, k), (_, n)) = (lhs.dim(), rhs.dim());
+    if m.saturating_mul(k).saturating_mul(n) < GEMM_PAR_CUTOFF {
+        return mat_mul_general(alpha, lhs, rhs, beta, c);
+    }
+    // the elements need to be Send + Sync to use multiple threads; cast to the
+    // concrete element types that use matrixmultiply.
+    macro_rules! par_gemm {
+        ($ty:ty) => {
+            if same_type::<A, $ty>() {
+                unsafe {
+                    let lhs_ = lhs.raw_view().cast::<$ty>().deref_into_view();
+                    let rhs_ = rhs.raw_view().cast::<$ty>().deref_into_view();
+                    let c_ = c.raw_view_mut().cast::<$ty>().deref_into_view_mut();
+                    mat_mul_par_tiles::<$ty>(cast_as(&alpha), lhs_, rhs_, cast_as(&beta), c_);
+                }
+                return;
+            }
+        };
+    }
+    par_gemm!(f32);
+    par_gemm!(f64);
+    par_gemm!(Complex32);
+    par_gemm!(Complex64);
+    mat_mul_general(alpha, lhs, rhs, beta, c)
+}
+
+/// C ← α A B + β C, splitting C into tiles that are computed in parallel
+/// in the current rayon thread pool.
+#[cfg(all(not(feature = "blas"), feature = "rayon"))]
+fn mat_mul_par_tiles<A>(
+    alpha: A,
+    lhs: ArrayView2<'_, A>,
+    rhs: ArrayView2<'_, A>,
+    beta: A,
+    mut c: ArrayViewMut2<'_, A>,
+) where
+    A: LinalgScalar + Send + Sync,
+{
+    let ((m, k), (_, n)) = (lhs.dim(), rhs.dim());
+    let split_rows = m >= n && m >= 2 * GEMM_PAR_MIN_TILE;
+    let split_cols = !split_rows && n >= 2 * GEMM_PAR_MIN_TILE;
+    if m.saturating_mul(k).saturating_mul(n) < GEMM_PAR_CUTOFF || !(split_rows || split_cols) {
+        return mat_mul_general(alpha, &lhs, &rhs, beta, &mut c);
+    }
+    if split_rows {
+        let (lhs1, lhs2) = lhs.split_at(Axis(0), m / 2);
+        let (c1, c2) = c.split_at(Axis(0), m / 2);
+        rayon::join(move || mat_mul_par_tiles(alpha, lhs1, rhs, beta, c1),
+                    move || mat_mul_par_tiles(alpha, lhs2, rhs, beta, c2));
+    } else {
+        let (rhs1, rhs2) = rhs.split_at(Axis(1), n / 2);
+        let (c1, c2) = c.split_at(Axis(1), n / 2);
+        rayon::join(move || mat_mul_par_tiles(alpha, lhs, rhs1, beta, c1),
+                    move || mat_mul_par_tiles(alpha, lhs, rhs2, beta, c2));
+    }
+}
+
 #[cfg(feature = "blas")]
 fn mat_mul_impl<A>(
     alpha: A,
@@ -370,12 +624,36 @@ fn mat_mul_impl<A>(
     c: &mut ArrayViewMut2<'_, A>,
 ) where
     A: LinalgScalar,
+{
+    if !mat_mul_blas(alpha, lhs, Conj::No, rhs, Conj::No, beta, c) {
+        mat_mul_general(alpha, lhs, rhs, beta, c)
+    }
+}
+
+/// C ← α op(A) op(B) + β C with BLAS gemm, where op conjugates the complex operands
+/// marked with `Conj::Yes`.
+///
+/// Return `false` if the element type, the sizes or the memory layouts are not
+/// supported. A conjugated operand is only supported if it is passed to BLAS
+/// transposed, since cblas has no option for conjugation without transposition.
+#[cfg(feature = "blas")]
+fn mat_mul_blas<A>(
+    alpha: A,
+    lhs: &ArrayView2<'_, A>,
+    mut conj_lhs: Conj,
+    rhs: &ArrayView2<'_, A>,
+    mut conj_rhs: Conj,
+    beta: A,
+    c: &mut ArrayViewMut2<'_, A>,
+) -> bool
+where
+    A: LinalgScalar,
 {
     // size cutoff for using BLAS
     let cut = GEMM_BLAS_CUTOFF;
     let ((mut m, a), (_, mut n)) = (lhs.dim(), rhs.dim());
-    if !(m > cut || n > cut || a > cut) || !(same_type::<A, f32>() || same_type::<A, f64>()) {
-        return mat_mul_general(alpha, lhs, rhs, beta, c);
+    if !(m > cut || n > cut || a > cut) || !blas_gemm_type::<A>() {
+        return false;
     }
     {
         // Use `c` for c-order and `f` for an f-order matrix
@@ -396,6 +674,7 @@ fn mat_mul_impl<A>(
             rhs_ = lhs_t;
             c_ = c_.reversed_axes();
             swap(&mut m, &mut n);
+            swap(&mut conj_lhs, &mut conj_rhs);
         } else if lhs_s0 == 1 && m == a {
             lhs_ = lhs_.reversed_axes();
             lhs_trans = CblasTrans;
@@ -403,9 +682,22 @@ fn mat_mul_impl<A>(
             rhs_ = rhs_.reversed_axes();
             rhs_trans = CblasTrans;
         }
+        // cblas can only conjugate an operand that it transposes
+        let conj_trans = |conj, trans| match (conj, trans) {
+            (Conj::No, trans) => Some(trans),
+            (Conj::Yes, CblasNoTrans) => None,
+            (Conj::Yes, _) => Some(CblasConjTrans),
+        };
+        let (lhs_trans, rhs_trans) =
+            match (conj_trans(conj_lhs, lhs_trans), conj_trans(conj_rhs, rhs_trans)) {
+                (Some(lhs_trans), Some(rhs_trans)) => (lhs_trans, rhs_trans),
+                _ => return false,
+            };
 
+        // $scalar converts alpha and beta to the scalar arguments of $gemm; the
+        // complex functions take them by pointer.
         macro_rules! gemm {
-            ($ty:ty, $gemm:ident) => {
+            ($ty:ty, $gemm:ident, |$s:ident| $scalar:expr) => {
                 if blas_row_major_2d::<$ty, _>(&lhs_)
                     && blas_row_major_2d::<$ty, _>(&rhs_)
                     && blas_row_major_2d::<$ty, _>(&c_)
@@ -436,24 +728,26 @@ fn mat_mul_impl<A>(
                             m as blas_index,               // m, rows of Op(a)
                             n as blas_index,               // n, cols of Op(b)
                             k as blas_index,               // k, cols of Op(a)
-                            cast_as(&alpha),               // alpha
+                            { let $s = &alpha; $scalar },  // alpha
                             lhs_.ptr.as_ptr() as *const _, // a
                             lhs_stride,                    // lda
                             rhs_.ptr.as_ptr() as *const _, // b
                             rhs_stride,                    // ldb
-                            cast_as(&beta),                // beta
+                            { let $s = &beta; $scalar },   // beta
                             c_.ptr.as_ptr() as *mut _,     // c
                             c_stride,                      // ldc
                         );
                     }
-                    return;
+                    return true;
                 }
             };
         }
-        gemm!(f32, cblas_sgemm);
-        gemm!(f64, cblas_dgemm);
+        gemm!(f32, cblas_sgemm, |x| cast_as(x));
+        gemm!(f64, cblas_dgemm, |x| cast_as(x));
+        gemm!(Complex32, cblas_cgemm, |x| x as *const A as *const _);
+        gemm!(Complex64, cblas_zgemm, |x| x as *const A as *const _);
     }
-    mat_mul_general(alpha, lhs, rhs, beta, c)
+    false
 }
 
 /// C ← α A B + β C
@@ -511,6 +805,50 @@ fn mat_mul_general<A>(
                 csc,
             );
         }
+    } else if same_type::<A, Complex32>() {
+        unsafe {
+            ::matrixmultiply::cgemm(
+                CGemmOption::Standard,
+                CGemmOption::Standard,
+                m,
+                k,
+                n,
+                complex_array(cast_as::<A, Complex32>(&alpha)),
+                ap as *const _,
+                lhs.strides()[0],
+                lhs.strides()[1],
+                bp as *const _,
+                rhs.strides()[0],
+                rhs.strides()[1],
+                complex_array(cast_as::<A, Complex32>(&beta)),
+                cp as *mut _,
+                rsc,
+                csc,
+            );
+        }
+    } else if same_type::<A, Complex64>() {
+        unsafe {
+            ::matrixmultiply::zgemm(
+                CGemmOption::Standard,
+                CGemmOption::Standard,
+                m,
+                k,
+                n,
+                complex_array(cast_as::<A, Complex64>(&alpha)),
+                ap as *const _,
+                lhs.strides()[0],
+                lhs.strides()[1],
+                bp as *const _,
+                rhs.strides()[0],
+                rhs.strides()[1],
+                complex_array(cast_as::<A, Complex64>(&beta)),
+                cp as *mut _,
+                rsc,
+                csc,
+            );
+        }
+    } else if m.saturating_mul(k).saturating_mul(n) >= GEMM_BLOCKED_CUTOFF {
+        gemm_blocked(alpha, lhs, rhs, beta, c);
     } else {
         // It's a no-op if `c` has zero length.
         if c.is_empty() {
@@ -551,11 +889,28 @@ fn mat_mul_general<A>(
 ///
 /// The array shapes must agree in the way that
 /// if `a` is *M* × *N*, then `b` is *N* × *K* and `c` is *M* × *K*.
+#[cfg_attr(
+    feature = "std",
+    doc = "See [`general_mat_mul_conj`] for products with conjugated complex operands."
+)]
 ///
 /// ***Panics*** if array shapes are not compatible<br>
-/// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64` when memory
-/// layout allows.  The default matrixmultiply backend is otherwise used for
-/// `f32, f64` for all memory layouts.
+/// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64`, `Complex<f32>`
+/// and `Complex<f64>` when memory layout allows.  The default matrixmultiply backend
+/// is otherwise used for these element types for all memory layouts. Without blas and
+/// with crate feature `rayon`, large products of such matrices are split into tiles
+/// that are computed in parallel in the current rayon thread pool.
+///
+/// Large products of other element types, like the integer types or user defined
+/// `LinalgScalar` types, are computed with a cache blocked kernel. The kernel
+/// accumulates the sums of products in the element type `A` itself, using only its
+/// `Add` and `Mul` implementations, so the result has the same overflow semantics as
+/// evaluating the sums with ordinary arithmetic: for the primitive integer types an
+/// overflow panics when debug assertions are enabled (which intermediate sums are
+/// formed is unspecified) and wraps around otherwise. Since wrapping addition and
+/// multiplication are associative, the wrapped result does not depend on the order
+/// of summation. For element types where addition is not associative, like the
+/// floating point types, the order of summation is unspecified.
 pub fn general_mat_mul<A, S1, S2, S3>(
     alpha: A,
     a: &ArrayBase<S1, Ix2>,
@@ -585,8 +940,9 @@ pub fn general_mat_mul<A, S1, S2, S3>(
 /// y an *M*-element column vector (one dimensional arrays).
 ///
 /// ***Panics*** if array shapes are not compatible<br>
-/// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64` when memory
-/// layout allows.
+/// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64`, `Complex<f32>`
+/// and `Complex<f64>` when memory layout allows. Products of complex matrices and
+/// vectors otherwise use the matrixmultiply backend.
 #[allow(clippy::collapsible_if)]
 pub fn general_mat_vec_mul<A, S1, S2, S3>(
     alpha: A,
@@ -605,6 +961,161 @@ pub fn general_mat_vec_mul<A, S1, S2, S3>(
     }
 }
 
+/// Whether an operand of a matrix product is used as is or conjugated.
+///
+#[cfg_attr(
+    feature = "std",
+    doc = "Used by [`general_mat_mul_conj`] and [`general_mat_vec_mul_conj`]."
+)]
+#[cfg_attr(
+    not(feature = "std"),
+    doc = "Used by `general_mat_mul_conj` and `general_mat_vec_mul_conj` (crate feature `std`)."
+)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Conj {
+    /// The operand as is
+    No,
+    /// The complex conjugate of the operand; the same as `No` for the real types
+    Yes,
+}
+
+/// General matrix-matrix multiplication with conjugated operands.
+///
+/// Compute C ← α op(A) op(B) + β C
+///
+/// where op(X) is the elementwise complex conjugate of X if its option is `Conj::Yes`,
+/// and X otherwise. Pass a transposed view, like `a.t()`, with `Conj::Yes` for the
+/// conjugate transpose Aᴴ.
+///
+/// The array shapes must agree in the same way as for [`general_mat_mul`].
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `gemm` with the conjugate transpose option when
+/// the conjugated operands are transposed in memory order, like `a.t()` of a c-order
+/// `a`. Otherwise, the conjugated operands are copied and conjugated, and the product
+/// is computed as by [`general_mat_mul`].
+///
+/// ```
+/// use ndarray::{array, Array2};
+/// use ndarray::linalg::{general_mat_mul_conj, Conj};
+/// use num_complex::Complex64;
+///
+/// let z = |re, im| Complex64::new(re, im);
+/// let a = array![[z(1., 2.), z(0., 1.)], [z(3., 0.), z(1., -1.)]];
+///
+/// // the Gram matrix Aᴴ A
+/// let mut g = Array2::zeros((2, 2));
+/// general_mat_mul_conj(z(1., 0.), &a.t(), Conj::Yes, &a, Conj::No, z(0., 0.), &mut g);
+/// assert_eq!(g, a.t().mapv(|x| x.conj()).dot(&a));
+/// assert_eq!(g, array![[z(14., 0.), z(5., -2.)], [z(5., 2.), z(3., 0.)]]);
+/// ```
+#[cfg(feature = "std")]
+pub fn general_mat_mul_conj<A, S1, S2, S3>(
+    alpha: A,
+    a: &ArrayBase<S1, Ix2>,
+    conj_a: Conj,
+    b: &ArrayBase<S2, Ix2>,
+    conj_b: Conj,
+    beta: A,
+    c: &mut ArrayBase<S3, Ix2>,
+) where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgFloat,
+{
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    let (m2, n2) = c.dim();
+    if k != k2 || m != m2 || n != n2 {
+        general_dot_shape_error(m, k, k2, n, m2, n2);
+    }
+    let (conj_a, conj_b) = (conj_option::<A>(conj_a), conj_option::<A>(conj_b));
+    let (a, b, mut c) = (a.view(), b.view(), c.view_mut());
+    #[cfg(feature = "blas")]
+    {
+        if mat_mul_blas(alpha, &a, conj_a, &b, conj_b, beta, &mut c) {
+            return;
+        }
+    }
+    let (a_conj, b_conj);
+    let a = match conj_a {
+        Conj::No => a,
+        Conj::Yes => {
+            a_conj = a.mapv(A::conj);
+            a_conj.view()
+        }
+    };
+    let b = match conj_b {
+        Conj::No => b,
+        Conj::Yes => {
+            b_conj = b.mapv(A::conj);
+            b_conj.view()
+        }
+    };
+    mat_mul_impl(alpha, &a, &b, beta, &mut c);
+}
+
+/// General matrix-vector multiplication with conjugated operands.
+///
+/// Compute y ← α op(A) op(x) + β y
+///
+/// where op(X) is the elementwise complex conjugate of X if its option is `Conj::Yes`,
+/// and X otherwise. Pass a transposed view, like `a.t()`, with `Conj::Yes` for the
+/// conjugate transpose Aᴴ.
+///
+/// The array shapes must agree in the same way as for [`general_mat_vec_mul`].
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* The product is computed as by [`general_mat_vec_mul`], without copying the
+/// matrix: since conj(A) x = conj(A conj(x)), a conjugated matrix is handled by
+/// conjugating the vectors instead.
+#[cfg(feature = "std")]
+pub fn general_mat_vec_mul_conj<A, S1, S2, S3>(
+    alpha: A,
+    a: &ArrayBase<S1, Ix2>,
+    conj_a: Conj,
+    x: &ArrayBase<S2, Ix1>,
+    conj_x: Conj,
+    beta: A,
+    y: &mut ArrayBase<S3, Ix1>,
+) where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgFloat,
+{
+    let ((m, k), k2) = (a.dim(), x.dim());
+    let m2 = y.dim();
+    if k != k2 || m != m2 {
+        general_dot_shape_error(m, k, k2, 1, m2, 1);
+    }
+    let (conj_a, conj_x) = (conj_option::<A>(conj_a), conj_option::<A>(conj_x));
+    let x_conj;
+    let x = if conj_a != conj_x {
+        x_conj = x.mapv(A::conj);
+        x_conj.view()
+    } else {
+        x.view()
+    };
+    if conj_a == Conj::Yes {
+        y.map_inplace(|y| *y = y.conj());
+        general_mat_vec_mul(alpha.conj(), a, &x, beta.conj(), y);
+        y.map_inplace(|y| *y = y.conj());
+    } else {
+        general_mat_vec_mul(alpha, a, &x, beta, y);
+    }
+}
+
+/// Return `conj`, or `Conj::No` for the real types, where conjugation has no effect.
+#[cfg(feature = "std")]
+fn conj_option<A: LinalgFloat>(conj: Conj) -> Conj {
+    if same_type::<A, A::Real>() {
+        Conj::No
+    } else {
+        conj
+    }
+}
+
 /// General matrix-vector multiplication
 ///
 /// Use a raw view for the destination vector, so that it can be uninitalized.
@@ -632,7 +1143,7 @@ unsafe fn general_mat_vec_mul_impl<A, S1, S2>(
     } else {
         #[cfg(feature = "blas")]
         macro_rules! gemv {
-            ($ty:ty, $gemv:ident) => {
+            ($ty:ty, $gemv:ident, |$s:ident| $scalar:expr) => {
                 if let Some(layout) = blas_layout::<$ty, _>(&a) {
                     if blas_compat_1d::<$ty, _>(&x) && blas_compat_1d::<$ty, _>(&y) {
                         // Determine stride between rows or columns. Note that the stride is
@@ -657,12 +1168,12 @@ unsafe fn general_mat_vec_mul_impl<A, S1, S2>(
                             a_trans,
                             m as blas_index,            // m, rows of Op(a)
                             k as blas_index,            // n, cols of Op(a)
-                            cast_as(&alpha),            // alpha
+                            { let $s = &alpha; $scalar }, // alpha
                             a.ptr.as_ptr() as *const _, // a
                             a_stride,                   // lda
                             x.ptr.as_ptr() as *const _, // x
                             x_stride,
-                            cast_as(&beta),           // beta
+                            { let $s = &beta; $scalar }, // beta
                             y.ptr.as_ptr() as *mut _, // x
                             y_stride,
                         );
@@ -672,9 +1183,43 @@ unsafe fn general_mat_vec_mul_impl<A, S1, S2>(
             };
         }
         #[cfg(feature = "blas")]
-        gemv!(f32, cblas_sgemv);
+        gemv!(f32, cblas_sgemv, |x| cast_as(x));
         #[cfg(feature = "blas")]
-        gemv!(f64, cblas_dgemv);
+        gemv!(f64, cblas_dgemv, |x| cast_as(x));
+        #[cfg(feature = "blas")]
+        gemv!(Complex32, cblas_cgemv, |x| x as *const A as *const _);
+        #[cfg(feature = "blas")]
+        gemv!(Complex64, cblas_zgemv, |x| x as *const A as *const _);
+
+        // complex: use the matrix multiplication kernel with a single column; y does
+        // not need to be initialized when beta is zero.
+        macro_rules! gemv_complex {
+            ($ty:ty, $gemm:ident) => {
+                if same_type::<A, $ty>() {
+                    ::matrixmultiply::$gemm(
+                        CGemmOption::Standard,
+                        CGemmOption::Standard,
+                        m,
+                        k,
+                        1,
+                        complex_array(cast_as::<A, $ty>(&alpha)),
+                        a.ptr.as_ptr() as *const _,
+                        a.strides()[0],
+                        a.strides()[1],
+                        x.ptr.as_ptr() as *const _,
+                        x.strides()[0],
+                        1,
+                        complex_array(cast_as::<A, $ty>(&beta)),
+                        y.ptr.as_ptr() as *mut _,
+                        y.strides()[0],
+                        1,
+                    );
+                    return;
+                }
+            };
+        }
+        gemv_complex!(Complex32, cgemm);
+        gemv_complex!(Complex64, zgemm);
 
         /* general */
 
@@ -691,12 +1236,566 @@ unsafe fn general_mat_vec_mul_impl<A, S1, S2>(
     }
 }
 
+/// A triangle of a square matrix.
+///
+/// Used by the triangular and symmetric matrix operations to select the part of the
+/// matrix that they reference or update.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Triangle {
+    /// The upper triangle, the elements `a[[i, j]]` where `i <= j`
+    Upper,
+    /// The lower triangle, the elements `a[[i, j]]` where `i >= j`
+    Lower,
+}
+
+impl Triangle {
+    /// Return the other triangle; the triangle that this one becomes in the
+    /// transposed matrix.
+    #[cfg(feature = "blas")]
+    fn transpose(self) -> Self {
+        match self {
+            Triangle::Upper => Triangle::Lower,
+            Triangle::Lower => Triangle::Upper,
+        }
+    }
+
+    #[cfg(feature = "blas")]
+    fn to_blas(self) -> CBLAS_UPLO {
+        match self {
+            Triangle::Upper => CBLAS_UPLO::CblasUpper,
+            Triangle::Lower => CBLAS_UPLO::CblasLower,
+        }
+    }
+
+    /// Return the range of column indices of row `i` that are in the triangle of a
+    /// matrix with `n` columns.
+    fn row_range(self, i: usize, n: usize) -> Range<usize> {
+        match self {
+            Triangle::Upper => i..n,
+            Triangle::Lower => 0..i + 1,
+        }
+    }
+}
+
+/// General rank-1 update of a matrix.
+///
+/// Compute A ← A + α x yᵀ
+///
+/// where x is an *M*-element vector, y is an *N*-element vector and A is a
+/// *M* × *N* matrix.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `ger` (`geru` for the complex types) for elements of
+/// `f32, f64`, `Complex<f32>` and `Complex<f64>` when memory layout allows.
+///
+/// ```
+/// use ndarray::arr1;
+/// use ndarray::arr2;
+/// use ndarray::linalg::general_outer_add;
+///
+/// let mut a = arr2(&[[1., 0.],
+///                    [0., 1.]]);
+/// general_outer_add(2., &arr1(&[1., 2.]), &arr1(&[3., 4.]), &mut a);
+/// assert_eq!(a, arr2(&[[7., 8.],
+///                      [12., 17.]]));
+/// ```
+pub fn general_outer_add<A, S1, S2, S3>(
+    alpha: A,
+    x: &ArrayBase<S1, Ix1>,
+    y: &ArrayBase<S2, Ix1>,
+    a: &mut ArrayBase<S3, Ix2>,
+) where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let (m, n) = a.dim();
+    if x.len() != m || y.len() != n {
+        panic!(
+            "ndarray: inputs {} and {} are not compatible with the {} × {} matrix \
+             of the outer product update",
+            x.len(),
+            y.len(),
+            m,
+            n
+        );
+    }
+    if m == 0 || n == 0 {
+        return;
+    }
+    let a = &mut a.view_mut();
+    #[cfg(feature = "blas")]
+    macro_rules! ger {
+        ($ty:ty, $ger:ident, |$s:ident| $scalar:expr) => {
+            if let Some(layout) = blas_layout::<$ty, _>(a) {
+                if blas_compat_1d::<$ty, _>(x) && blas_compat_1d::<$ty, _>(y) {
+                    unsafe {
+                        let (x_ptr, _, incx) =
+                            blas_1d_params(x.ptr.as_ptr(), x.len(), x.strides()[0]);
+                        let (y_ptr, _, incy) =
+                            blas_1d_params(y.ptr.as_ptr(), y.len(), y.strides()[0]);
+                        let lda = blas_stride(a, layout);
+                        blas_sys::$ger(
+                            layout,
+                            m as blas_index,
+                            n as blas_index,
+                            { let $s = &alpha; $scalar },
+                            x_ptr as *const _,
+                            incx,
+                            y_ptr as *const _,
+                            incy,
+                            a.as_mut_ptr() as *mut _,
+                            lda,
+                        );
+                    }
+                    return;
+                }
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    ger!(f32, cblas_sger, |s| cast_as(s));
+    #[cfg(feature = "blas")]
+    ger!(f64, cblas_dger, |s| cast_as(s));
+    #[cfg(feature = "blas")]
+    ger!(Complex32, cblas_cgeru, |s| s as *const A as *const _);
+    #[cfg(feature = "blas")]
+    ger!(Complex64, cblas_zgeru, |s| s as *const A as *const _);
+
+    Zip::from(a.rows_mut())
+        .and(x)
+        .for_each(|mut row, &xi| row.scaled_add(alpha * xi, y));
+}
+
+/// Symmetric rank-k update of a matrix.
+///
+/// Compute C ← α A Aᵀ + β C
+///
+/// where A is a *N* × *K* matrix and C is a symmetric *N* × *N* matrix. Only the
+/// triangle `uplo` of C is referenced and updated; the other triangle is left as it
+/// is. When `beta` is zero, the triangle of C is overwritten without being read.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `syrk` for elements of `f32, f64`, `Complex<f32>` and
+/// `Complex<f64>` when memory layout allows.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{symmetric_rank_k_update, Triangle};
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let mut c = arr2(&[[1., -1.],
+///                    [-1., 1.]]);
+/// symmetric_rank_k_update(Triangle::Lower, 1., &a, 1., &mut c);
+/// assert_eq!(c, arr2(&[[6., -1.],
+///                      [10., 26.]]));
+/// ```
+pub fn symmetric_rank_k_update<A, S1, S2>(
+    uplo: Triangle,
+    alpha: A,
+    a: &ArrayBase<S1, Ix2>,
+    beta: A,
+    c: &mut ArrayBase<S2, Ix2>,
+) where
+    S1: Data<Elem = A>,
+    S2: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let (n, k) = a.dim();
+    let (n1, n2) = c.dim();
+    if n1 != n || n2 != n {
+        general_dot_shape_error(n, k, k, n, n1, n2);
+    }
+    if n == 0 {
+        return;
+    }
+    let c = &mut c.view_mut();
+    #[cfg(feature = "blas")]
+    macro_rules! syrk {
+        ($ty:ty, $syrk:ident, |$s:ident| $scalar:expr) => {
+            if let Some(layout) = blas_layout::<$ty, _>(c) {
+                if let Some((a_, trans)) = blas_operand::<$ty, _>(a, layout) {
+                    if k > 0 {
+                        unsafe {
+                            blas_sys::$syrk(
+                                layout,
+                                uplo.to_blas(),
+                                trans,
+                                n as blas_index,
+                                k as blas_index,
+                                { let $s = &alpha; $scalar },
+                                a_.as_ptr() as *const _,
+                                blas_stride(&a_, layout),
+                                { let $s = &beta; $scalar },
+                                c.as_mut_ptr() as *mut _,
+                                blas_stride(c, layout),
+                            );
+                        }
+                        return;
+                    }
+                }
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    syrk!(f32, cblas_ssyrk, |s| cast_as(s));
+    #[cfg(feature = "blas")]
+    syrk!(f64, cblas_dsyrk, |s| cast_as(s));
+    #[cfg(feature = "blas")]
+    syrk!(Complex32, cblas_csyrk, |s| s as *const A as *const _);
+    #[cfg(feature = "blas")]
+    syrk!(Complex64, cblas_zsyrk, |s| s as *const A as *const _);
+
+    for (i, mut c_row) in c.rows_mut().into_iter().enumerate() {
+        let a_i = a.row(i);
+        for j in uplo.row_range(i, n) {
+            let prod = alpha * a_i.dot(&a.row(j));
+            let elt = &mut c_row[j];
+            *elt = if beta.is_zero() {
+                prod
+            } else {
+                beta * *elt + prod
+            };
+        }
+    }
+}
+
+/// Triangular matrix multiplication.
+///
+/// Compute B ← α A B
+///
+/// where A is a triangular *M* × *M* matrix and B is a *M* × *N* matrix. Only the
+/// triangle `uplo` of A is referenced.
+///
+/// To multiply from the right, use the transposes: B A = (Aᵀ Bᵀ)ᵀ, and the
+/// triangle of Aᵀ is the opposite one of A.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `trmm` for elements of `f32, f64`, `Complex<f32>` and
+/// `Complex<f64>` when memory layout allows.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{triangular_mat_mul, Triangle};
+///
+/// // the upper triangle is not referenced
+/// let a = arr2(&[[1., 9.],
+///                [2., 3.]]);
+/// let mut b = arr2(&[[1., 2.],
+///                    [3., 4.]]);
+/// triangular_mat_mul(Triangle::Lower, 1., &a, &mut b);
+/// assert_eq!(b, arr2(&[[1., 2.],
+///                      [11., 16.]]));
+/// ```
+pub fn triangular_mat_mul<A, S1, S2>(
+    uplo: Triangle,
+    alpha: A,
+    a: &ArrayBase<S1, Ix2>,
+    b: &mut ArrayBase<S2, Ix2>,
+) where
+    S1: Data<Elem = A>,
+    S2: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let (m, n) = b.dim();
+    if a.dim() != (m, m) {
+        let (m1, m2) = a.dim();
+        general_dot_shape_error(m1, m2, m, n, m, n);
+    }
+    if m == 0 || n == 0 {
+        return;
+    }
+    let b = &mut b.view_mut();
+    #[cfg(feature = "blas")]
+    macro_rules! trmm {
+        ($ty:ty, $trmm:ident, |$s:ident| $scalar:expr) => {
+            if let Some(layout) = blas_layout::<$ty, _>(b) {
+                if let Some((a_, trans)) = blas_operand::<$ty, _>(a, layout) {
+                    let uplo = match trans {
+                        CblasNoTrans => uplo,
+                        _ => uplo.transpose(),
+                    };
+                    unsafe {
+                        blas_sys::$trmm(
+                            layout,
+                            CblasLeft,
+                            uplo.to_blas(),
+                            trans,
+                            CblasNonUnit,
+                            m as blas_index,
+                            n as blas_index,
+                            { let $s = &alpha; $scalar },
+                            a_.as_ptr() as *const _,
+                            blas_stride(&a_, layout),
+                            b.as_mut_ptr() as *mut _,
+                            blas_stride(b, layout),
+                        );
+                    }
+                    return;
+                }
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    trmm!(f32, cblas_strmm, |s| cast_as(s));
+    #[cfg(feature = "blas")]
+    trmm!(f64, cblas_dtrmm, |s| cast_as(s));
+    #[cfg(feature = "blas")]
+    trmm!(Complex32, cblas_ctrmm, |s| s as *const A as *const _);
+    #[cfg(feature = "blas")]
+    trmm!(Complex64, cblas_ztrmm, |s| s as *const A as *const _);
+
+    // Row i of the product only uses rows of B that are in the triangle; visit the
+    // rows in the order where those are not yet overwritten.
+    let mut update_row = |i: usize| {
+        let (mut b_i, rest) = match uplo {
+            Triangle::Upper => {
+                let (b_i, rest) = b.view_mut().split_at(Axis(0), i + 1);
+                (b_i.index_axis_move(Axis(0), i), rest)
+            }
+            Triangle::Lower => {
+                let (rest, b_i) = b.view_mut().split_at(Axis(0), i);
+                (b_i.index_axis_move(Axis(0), 0), rest)
+            }
+        };
+        let a_ii = alpha * a[[i, i]];
+        b_i.map_inplace(|x| *x = a_ii * *x);
+        let a_i = a.row(i);
+        let a_i = match uplo {
+            Triangle::Upper => a_i.slice_move(s![i + 1..]),
+            Triangle::Lower => a_i.slice_move(s![..i]),
+        };
+        for (&a_ij, b_j) in a_i.iter().zip(rest.rows()) {
+            b_i.scaled_add(alpha * a_ij, &b_j);
+        }
+    };
+    match uplo {
+        Triangle::Upper => (0..m).for_each(&mut update_row),
+        Triangle::Lower => (0..m).rev().for_each(&mut update_row),
+    }
+}
+
+/// Solve a triangular system of equations.
+///
+/// Compute B ← A⁻¹ B, the solution X of A X = B,
+///
+/// where A is a triangular *M* × *M* matrix and B is either a vector with *M*
+/// elements or a *M* × *N* matrix. Only the triangle `uplo` of A is referenced.
+///
+/// The solution is computed by substitution, dividing by the elements of the
+/// diagonal of A. It is up to the caller to make sure that A is not singular.
+///
+/// ***Panics*** if array shapes are not compatible or if `b` has more than two
+/// dimensions.<br>
+/// *Note:* If enabled, uses blas `trsv` or `trsm` for elements of `f32, f64`,
+/// `Complex<f32>` and `Complex<f64>` when memory layout allows.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::{triangular_solve, Triangle};
+///
+/// let a = arr2(&[[2., 1.],
+///                [0., 4.]]);
+/// let mut b = arr1(&[4., 8.]);
+/// triangular_solve(Triangle::Upper, &a, &mut b);
+/// assert_eq!(b, arr1(&[1., 2.]));
+/// ```
+pub fn triangular_solve<A, S1, S2, D>(
+    uplo: Triangle,
+    a: &ArrayBase<S1, Ix2>,
+    b: &mut ArrayBase<S2, D>,
+) where
+    S1: Data<Elem = A>,
+    S2: DataMut<Elem = A>,
+    A: LinalgScalar,
+    D: Dimension,
+{
+    let (m1, m2) = a.dim();
+    if b.ndim() == 0 || b.ndim() > 2 || m1 != m2 || b.len_of(Axis(0)) != m1 {
+        panic!(
+            "ndarray: input {} × {} and right hand side of shape {:?} are not compatible \
+             for solving a triangular system",
+            m1,
+            m2,
+            b.shape()
+        );
+    }
+    let a = a.view();
+    if b.ndim() == 1 {
+        triangular_solve_vec(uplo, false, &a, b.view_mut().into_dimensionality().unwrap());
+    } else {
+        triangular_solve_mat(uplo, false, &a, b.view_mut().into_dimensionality().unwrap());
+    }
+}
+
+/// Solve A x = b for x, in place in `b`; if `unit_diagonal` is true, the diagonal of A
+/// is not referenced and taken to be all ones.
+fn triangular_solve_vec<A>(
+    uplo: Triangle,
+    unit_diagonal: bool,
+    a: &ArrayView2<'_, A>,
+    mut b: ArrayViewMut1<'_, A>,
+) where
+    A: LinalgScalar,
+{
+    let m = b.len();
+    if m == 0 {
+        return;
+    }
+    #[cfg(feature = "blas")]
+    macro_rules! trsv {
+        ($ty:ty, $trsv:ident) => {
+            if let Some(layout) = blas_layout::<$ty, _>(a) {
+                if blas_compat_1d::<$ty, _>(&b) {
+                    unsafe {
+                        let (b_ptr, _, incb) =
+                            blas_1d_params(b.as_ptr(), m, b.strides()[0]);
+                        blas_sys::$trsv(
+                            layout,
+                            uplo.to_blas(),
+                            CblasNoTrans,
+                            blas_diag(unit_diagonal),
+                            m as blas_index,
+                            a.as_ptr() as *const _,
+                            blas_stride(a, layout),
+                            b_ptr as *mut _,
+                            incb,
+                        );
+                    }
+                    return;
+                }
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    trsv!(f32, cblas_strsv);
+    #[cfg(feature = "blas")]
+    trsv!(f64, cblas_dtrsv);
+    #[cfg(feature = "blas")]
+    trsv!(Complex32, cblas_ctrsv);
+    #[cfg(feature = "blas")]
+    trsv!(Complex64, cblas_ztrsv);
+
+    let mut solve = |i: usize| {
+        let a_i = a.row(i);
+        let (a_i, b_solved) = match uplo {
+            Triangle::Upper => (a_i.slice_move(s![i + 1..]), b.slice(s![i + 1..])),
+            Triangle::Lower => (a_i.slice_move(s![..i]), b.slice(s![..i])),
+        };
+        let x = b[i] - a_i.dot(&b_solved);
+        b[i] = if unit_diagonal { x } else { x / a[[i, i]] };
+    };
+    match uplo {
+        Triangle::Upper => (0..m).rev().for_each(&mut solve),
+        Triangle::Lower => (0..m).for_each(&mut solve),
+    }
+}
+
+/// Solve A X = B for X, in place in `b`; if `unit_diagonal` is true, the diagonal of
+/// A is not referenced and taken to be all ones.
+pub(super) fn triangular_solve_mat<A>(
+    uplo: Triangle,
+    unit_diagonal: bool,
+    a: &ArrayView2<'_, A>,
+    mut b: ArrayViewMut2<'_, A>,
+) where
+    A: LinalgScalar,
+{
+    let (m, n) = b.dim();
+    if m == 0 || n == 0 {
+        return;
+    }
+    #[cfg(feature = "blas")]
+    macro_rules! trsm {
+        ($ty:ty, $trsm:ident, |$s:ident| $scalar:expr) => {
+            if let Some(layout) = blas_layout::<$ty, _>(&b) {
+                if let Some((a_, trans)) = blas_operand::<$ty, _>(a, layout) {
+                    let uplo = match trans {
+                        CblasNoTrans => uplo,
+                        _ => uplo.transpose(),
+                    };
+                    unsafe {
+                        blas_sys::$trsm(
+                            layout,
+                            CblasLeft,
+                            uplo.to_blas(),
+                            trans,
+                            blas_diag(unit_diagonal),
+                            m as blas_index,
+                            n as blas_index,
+                            { let $s = &A::one(); $scalar },
+                            a_.as_ptr() as *const _,
+                            blas_stride(&a_, layout),
+                            b.as_mut_ptr() as *mut _,
+                            blas_stride(&b, layout),
+                        );
+                    }
+                    return;
+                }
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    trsm!(f32, cblas_strsm, |s| cast_as(s));
+    #[cfg(feature = "blas")]
+    trsm!(f64, cblas_dtrsm, |s| cast_as(s));
+    #[cfg(feature = "blas")]
+    trsm!(Complex32, cblas_ctrsm, |s| s as *const A as *const _);
+    #[cfg(feature = "blas")]
+    trsm!(Complex64, cblas_ztrsm, |s| s as *const A as *const _);
+
+    let mut solve = |i: usize| {
+        let (mut b_i, solved) = match uplo {
+            Triangle::Upper => {
+                let (b_i, solved) = b.view_mut().split_at(Axis(0), i + 1);
+                (b_i.index_axis_move(Axis(0), i), solved)
+            }
+            Triangle::Lower => {
+                let (solved, b_i) = b.view_mut().split_at(Axis(0), i);
+                (b_i.index_axis_move(Axis(0), 0), solved)
+            }
+        };
+        let a_i = a.row(i);
+        let a_i = match uplo {
+            Triangle::Upper => a_i.slice_move(s![i + 1..]),
+            Triangle::Lower => a_i.slice_move(s![..i]),
+        };
+        for (&a_ij, x_j) in a_i.iter().zip(solved.rows()) {
+            b_i.scaled_add(A::zero() - a_ij, &x_j);
+        }
+        if !unit_diagonal {
+            let a_ii = a[[i, i]];
+            b_i.map_inplace(|x| *x = *x / a_ii);
+        }
+    };
+    match uplo {
+        Triangle::Upper => (0..m).rev().for_each(&mut solve),
+        Triangle::Lower => (0..m).for_each(&mut solve),
+    }
+}
+
 #[inline(always)]
 /// Return `true` if `A` and `B` are the same type
 fn same_type<A: 'static, B: 'static>() -> bool {
     TypeId::of::<A>() == TypeId::of::<B>()
 }
 
+/// Return `true` if `A` is one of the element types of the BLAS gemm functions
+#[cfg(feature = "blas")]
+fn blas_gemm_type<A: 'static>() -> bool {
+    same_type::<A, f32>()
+        || same_type::<A, f64>()
+        || same_type::<A, Complex32>()
+        || same_type::<A, Complex64>()
+}
+
+/// Return the complex number in the representation of matrixmultiply
+fn complex_array<A: Copy>(z: Complex<A>) -> [A; 2] {
+    [z.re, z.im]
+}
+
 // Read pointer to type `A` as type `B`.
 //
 // **Panics** if `A` and `B` are not the same type
@@ -783,6 +1882,55 @@ fn is_blas_2d(dim: &Ix2, stride: &Ix2, order: MemoryOrder) -> bool {
     true
 }
 
+/// Return a view of `a`, or of its transpose, that has the memory layout `layout`,
+/// and the BLAS operation that turns that view back into `a`.
+#[cfg(feature = "blas")]
+fn blas_operand<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    layout: CBLAS_LAYOUT,
+) -> Option<(ArrayView2<'_, S::Elem>, CBLAS_TRANSPOSE)>
+where
+    S: Data,
+    A: 'static,
+    S::Elem: 'static,
+{
+    let has_layout = |a: &ArrayView2<'_, S::Elem>| match layout {
+        CBLAS_LAYOUT::CblasRowMajor => blas_row_major_2d::<A, _>(a),
+        CBLAS_LAYOUT::CblasColMajor => blas_column_major_2d::<A, _>(a),
+    };
+    let a = a.view();
+    if has_layout(&a) {
+        Some((a, CblasNoTrans))
+    } else if has_layout(&a.t()) {
+        Some((a.reversed_axes(), CblasTrans))
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "blas")]
+fn blas_diag(unit_diagonal: bool) -> CBLAS_DIAG {
+    if unit_diagonal {
+        CBLAS_DIAG::CblasUnit
+    } else {
+        CBLAS_DIAG::CblasNonUnit
+    }
+}
+
+/// Return the leading dimension argument for the matrix `a` with memory layout
+/// `layout`.
+///
+/// The stride is adjusted to at least the length of the rows or columns, since the
+/// stride of a trivial (length 1) dimension may be arbitrary.
+#[cfg(feature = "blas")]
+fn blas_stride<S: RawData>(a: &ArrayBase<S, Ix2>, layout: CBLAS_LAYOUT) -> blas_index {
+    let (m, n) = a.dim();
+    match layout {
+        CBLAS_LAYOUT::CblasRowMajor => a.strides()[0].max(n as isize) as blas_index,
+        CBLAS_LAYOUT::CblasColMajor => a.strides()[1].max(m as isize) as blas_index,
+    }
+}
+
 #[cfg(feature = "blas")]
 fn blas_layout<A, S>(a: &ArrayBase<S, Ix2>) -> Option<CBLAS_LAYOUT>
 where
@@ -0,0 +1,342 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::min;
+
+use alloc::vec::Vec;
+use num_traits::{Float, Zero};
+
+use crate::imp_prelude::*;
+
+use super::impl_linalg::triangular_solve_mat;
+use super::util::{solve_rhs, swap_rows};
+use super::{general_mat_mul, general_outer_add, LinalgError, LinalgFloat, Triangle};
+
+/// The number of columns in a panel of the blocked factorization.
+///
+/// The columns of a panel are eliminated one at a time, and the rest of the matrix
+/// is updated once per panel, with a matrix multiplication.
+const PANEL: usize = 32;
+
+/// LU decomposition of a square matrix, with partial pivoting.
+///
+/// The decomposition is P A = L U, where P is a permutation matrix, L is lower
+/// triangular with ones on the diagonal, and U is upper triangular. It exists
+/// for every square matrix, singular or not; the matrix is singular if and only if
+/// U has a zero on its diagonal.
+///
+/// The decomposition can be reused to solve several systems of equations with the
+/// same matrix.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::Lu;
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let lu = Lu::new(&a).unwrap();
+/// assert_eq!(lu.permutation(), vec![1, 0]);
+/// assert_eq!(lu.solve(&arr1(&[5., 11.])).unwrap(), arr1(&[1., 2.]));
+/// assert_eq!(lu.det(), -2.);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Lu<A> {
+    /// L below the diagonal (without its unit diagonal), and U on and above it
+    lu: Array2<A>,
+    /// Row `i` was swapped with row `pivots[i]` in step `i` of the elimination
+    pivots: Vec<usize>,
+    /// Whether the number of row swaps is odd
+    odd_swaps: bool,
+}
+
+impl<A> Lu<A>
+where
+    A: LinalgFloat,
+{
+    /// Compute the LU decomposition of the square matrix `a`.
+    ///
+    /// **Errors** if `a` is not square.
+    pub fn new<S>(a: &ArrayBase<S, Ix2>) -> Result<Self, LinalgError>
+    where
+        S: Data<Elem = A>,
+    {
+        let (n, m) = a.dim();
+        LinalgError::check_square(n, m)?;
+        let mut lu = a.to_owned();
+        let mut pivots = Vec::with_capacity(n);
+        let odd_swaps = factorize(lu.view_mut(), &mut pivots);
+        Ok(Lu {
+            lu,
+            pivots,
+            odd_swaps,
+        })
+    }
+
+    /// Return the lower triangular factor L.
+    pub fn l(&self) -> Array2<A> {
+        Array::from_shape_fn(self.lu.raw_dim(), |(i, j)| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => self.lu[[i, j]],
+            std::cmp::Ordering::Equal => A::one(),
+            std::cmp::Ordering::Less => A::zero(),
+        })
+    }
+
+    /// Return the upper triangular factor U.
+    pub fn u(&self) -> Array2<A> {
+        Array::from_shape_fn(self.lu.raw_dim(), |(i, j)| {
+            if i <= j {
+                self.lu[[i, j]]
+            } else {
+                A::zero()
+            }
+        })
+    }
+
+    /// Return the permutation P as a vector `p` of row indices: row `i` of P A is
+    /// row `p[i]` of A.
+    pub fn permutation(&self) -> Vec<usize> {
+        let mut p: Vec<usize> = (0..self.pivots.len()).collect();
+        for (i, &pivot) in self.pivots.iter().enumerate() {
+            p.swap(i, pivot);
+        }
+        p
+    }
+
+    /// Return `true` if the matrix is singular.
+    pub fn is_singular(&self) -> bool {
+        self.lu.diag().iter().any(|x| x.is_zero())
+    }
+
+    /// Solve the system of equations A x = b for x, where `b` is a vector, or the
+    /// systems A X = B for X, where `b` is a matrix with a right hand side in each
+    /// column.
+    ///
+    /// **Errors** if the matrix is singular, or if `b` is not a vector or matrix
+    /// with as many rows as the matrix.
+    pub fn solve<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        solve_rhs(self.lu.nrows(), b, "solve", |x| {
+            if self.is_singular() {
+                return Err(LinalgError::Singular);
+            }
+            self.solve_inplace(x);
+            Ok(())
+        })
+    }
+
+    /// Return the inverse of the matrix.
+    ///
+    /// **Errors** if the matrix is singular.
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError> {
+        self.solve(&Array2::eye(self.lu.nrows()))
+    }
+
+    /// Return the determinant of the matrix.
+    pub fn det(&self) -> A {
+        let det = self.lu.diag().iter().fold(A::one(), |acc, &x| acc * x);
+        if self.odd_swaps {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Return the sign and the natural logarithm of the absolute value of the
+    /// determinant of the matrix.
+    ///
+    /// The determinant is `sign * logabsdet.exp()`. For complex matrices, the
+    /// sign is a complex number with absolute value one. Computing the logarithm is
+    /// useful when the determinant itself would overflow or underflow.
+    ///
+    /// If the matrix is singular, the sign is zero and the logarithm is negative
+    /// infinity.
+    pub fn slogdet(&self) -> (A, A::Real) {
+        if self.is_singular() {
+            return (A::zero(), A::Real::neg_infinity());
+        }
+        let mut sign = if self.odd_swaps { -A::one() } else { A::one() };
+        let mut logabsdet = A::Real::zero();
+        for &x in self.lu.diag() {
+            let abs = x.abs();
+            sign = sign * (x / A::from_real(abs));
+            logabsdet += abs.ln();
+        }
+        (sign, logabsdet)
+    }
+
+    /// Overwrite `b` with the solution of A X = B.
+    fn solve_inplace(&self, mut b: ArrayViewMut2<'_, A>) {
+        for (i, &pivot) in self.pivots.iter().enumerate() {
+            if pivot != i {
+                swap_rows(&mut b, i, pivot);
+            }
+        }
+        triangular_solve_mat(Triangle::Lower, true, &self.lu.view(), b.view_mut());
+        triangular_solve_mat(Triangle::Upper, false, &self.lu.view(), b);
+    }
+}
+
+/// Overwrite `a` with its LU decomposition, pushing the pivot rows to `pivots`.
+///
+/// Return `true` if the number of row swaps is odd.
+fn factorize<A>(mut a: ArrayViewMut2<'_, A>, pivots: &mut Vec<usize>) -> bool
+where
+    A: LinalgFloat,
+{
+    let n = a.nrows();
+    let mut odd_swaps = false;
+    for j0 in (0..n).step_by(PANEL) {
+        let j1 = min(j0 + PANEL, n);
+        for j in j0..j1 {
+            // pivot on the element of the column with the largest absolute value
+            let mut pivot = j;
+            let mut max = a[[j, j]].abs();
+            for (i, x) in a.slice(s![j + 1.., j]).iter().enumerate() {
+                if x.abs() > max {
+                    pivot = j + 1 + i;
+                    max = x.abs();
+                }
+            }
+            pivots.push(pivot);
+            if pivot != j {
+                swap_rows(&mut a, j, pivot);
+                odd_swaps = !odd_swaps;
+            }
+            let a_jj = a[[j, j]];
+            if a_jj.is_zero() {
+                // the column is already eliminated
+                continue;
+            }
+            a.slice_mut(s![j + 1.., j]).map_inplace(|x| *x = *x / a_jj);
+            // eliminate the column from the rest of the panel
+            let (top, bottom) = a.view_mut().split_at(Axis(0), j + 1);
+            let (l, mut rest) = bottom.split_at(Axis(1), j + 1);
+            general_outer_add(
+                -A::one(),
+                &l.column(j),
+                &top.slice(s![j, j + 1..j1]),
+                &mut rest.slice_mut(s![.., ..j1 - j - 1]),
+            );
+        }
+        if j1 < n {
+            let (top, bottom) = a.view_mut().split_at(Axis(0), j1);
+            let (l11, mut u12) = top.slice_move(s![j0.., ..]).split_at(Axis(1), j1);
+            // U12 ← L11⁻¹ A12
+            triangular_solve_mat(
+                Triangle::Lower,
+                true,
+                &l11.slice(s![.., j0..]),
+                u12.view_mut(),
+            );
+            // A22 ← A22 − L21 U12
+            let (l21, mut a22) = bottom.split_at(Axis(1), j1);
+            general_mat_mul(-A::one(), &l21.slice(s![.., j0..]), &u12, A::one(), &mut a22);
+        }
+    }
+    odd_swaps
+}
+
+/// Solve the system of equations A x = b for x, where `b` is a vector, or the systems
+/// A X = B for X, where `b` is a matrix with a right hand side in each column.
+///
+/// This computes the [LU decomposition](Lu) of A. To solve several systems with the
+/// same matrix, compute the decomposition once and use its `solve` method.
+///
+/// **Errors** if `a` is not square or is singular, or if `b` is not a vector or a
+/// matrix with as many rows as `a`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::solve;
+///
+/// let a = arr2(&[[3., 1.],
+///                [1., 2.]]);
+/// let x = solve(&a, &arr1(&[9., 8.])).unwrap();
+/// assert_eq!(x, arr1(&[2., 3.]));
+/// ```
+pub fn solve<A, S1, S2, D>(
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, D>,
+) -> Result<Array<A, D>, LinalgError>
+where
+    A: LinalgFloat,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    Lu::new(a)?.solve(b)
+}
+
+/// Return the inverse of the square matrix `a`.
+///
+/// **Errors** if `a` is not square or is singular.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::inv;
+///
+/// let a = arr2(&[[2., 0.],
+///                [1., 4.]]);
+/// assert_eq!(inv(&a).unwrap(), arr2(&[[0.5, 0.],
+///                                     [-0.125, 0.25]]));
+/// ```
+pub fn inv<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array2<A>, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    Lu::new(a)?.inv()
+}
+
+/// Return the determinant of the square matrix `a`.
+///
+/// **Errors** if `a` is not square.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::det;
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// assert_eq!(det(&a).unwrap(), -2.);
+/// ```
+pub fn det<A, S>(a: &ArrayBase<S, Ix2>) -> Result<A, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    Ok(Lu::new(a)?.det())
+}
+
+/// Return the sign and the natural logarithm of the absolute value of the
+/// determinant of the square matrix `a`.
+///
+/// See [`Lu::slogdet`] for details.
+///
+/// **Errors** if `a` is not square.
+///
+/// ```
+/// use ndarray::Array2;
+/// use ndarray::linalg::slogdet;
+///
+/// // the determinant, 1e-400, is too small for an f64
+/// let a = Array2::<f64>::eye(100) * 1e-4;
+/// let (sign, logabsdet) = slogdet(&a).unwrap();
+/// assert_eq!(sign, 1.);
+/// assert!((logabsdet - -400. * 10f64.ln()).abs() < 1e-9);
+/// ```
+pub fn slogdet<A, S>(a: &ArrayBase<S, Ix2>) -> Result<(A, A::Real), LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    Ok(Lu::new(a)?.slogdet())
+}
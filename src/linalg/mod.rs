@@ -7,9 +7,59 @@
 // except according to those terms.
 
 //! Linear algebra.
+//!
+//! Matrix and vector products for any [`LinalgScalar`](crate::LinalgScalar)
+//! element type, and, with crate feature `std`, decompositions of real and complex
+//! floating point matrices (see [`LinalgFloat`]) that are implemented in Rust.
 
 pub use self::impl_linalg::general_mat_mul;
+#[cfg(feature = "std")]
+pub use self::impl_linalg::general_mat_mul_conj;
 pub use self::impl_linalg::general_mat_vec_mul;
+#[cfg(feature = "std")]
+pub use self::impl_linalg::general_mat_vec_mul_conj;
+pub use self::impl_linalg::general_outer_add;
+pub use self::impl_linalg::symmetric_rank_k_update;
+pub use self::impl_linalg::triangular_mat_mul;
+pub use self::impl_linalg::triangular_solve;
+pub use self::impl_linalg::Conj;
 pub use self::impl_linalg::Dot;
+pub use self::impl_linalg::Triangle;
 
+#[cfg(feature = "std")]
+pub use self::cholesky::{cho_solve, cholesky, solve_triangular, Cholesky};
+#[cfg(feature = "std")]
+pub use self::eigh::{eigh, eigvalsh};
+#[cfg(feature = "std")]
+pub use self::error::LinalgError;
+#[cfg(feature = "std")]
+pub use self::expm::{expm, matrix_power};
+#[cfg(feature = "std")]
+pub use self::lu::{det, inv, slogdet, solve, Lu};
+#[cfg(feature = "std")]
+pub use self::qr::{lstsq, qr, LeastSquares, Qr, QrMode};
+#[cfg(feature = "std")]
+pub use self::scalar::LinalgFloat;
+#[cfg(feature = "std")]
+pub use self::svd::{cond, matrix_rank, nuclear_norm, pinv, spectral_norm, svd, SvdMode};
+
+#[cfg(feature = "std")]
+mod cholesky;
+#[cfg(feature = "std")]
+mod eigh;
+#[cfg(feature = "std")]
+mod error;
+#[cfg(feature = "std")]
+mod expm;
+mod gemm_blocked;
 mod impl_linalg;
+#[cfg(feature = "std")]
+mod lu;
+#[cfg(feature = "std")]
+mod qr;
+#[cfg(feature = "std")]
+mod scalar;
+#[cfg(feature = "std")]
+mod svd;
+#[cfg(feature = "std")]
+mod util;
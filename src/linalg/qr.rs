@@ -0,0 +1,283 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::{max, min};
+
+use alloc::vec::Vec;
+use num_traits::{Float, NumCast, Zero};
+
+use crate::imp_prelude::*;
+
+use super::impl_linalg::triangular_solve_mat;
+use super::util::{apply_reflector, make_reflector, reflector, swap_rows};
+use super::{LinalgError, LinalgFloat, Triangle};
+
+/// The size of the factors of a [`Qr`] decomposition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum QrMode {
+    /// For a *M* × *N* matrix with *K* = min(*M*, *N*): Q is *M* × *K* and R is
+    /// *K* × *N*.
+    Reduced,
+    /// Q is *M* × *M* and R is *M* × *N*.
+    Complete,
+}
+
+/// QR decomposition of a matrix, computed with Householder reflections.
+///
+/// The decomposition is A = Q R, where Q has orthonormal columns (it is unitary for
+/// complex matrices) and R is upper triangular. Q is represented by the reflections
+/// and is only formed when it is requested.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{Qr, QrMode};
+///
+/// let a = arr2(&[[3., 1.],
+///                [4., 2.],
+///                [0., 5.]]);
+/// let qr = Qr::new(&a);
+/// let (q, r) = (qr.q(QrMode::Reduced), qr.r(QrMode::Reduced));
+/// assert_eq!(q.dim(), (3, 2));
+/// assert_eq!(r.dim(), (2, 2));
+/// assert!((q.dot(&r) - &a).iter().all(|x: &f64| x.abs() < 1e-12));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Qr<A> {
+    /// The transpose of A, overwritten with R in its lower triangle and the
+    /// reflection vectors above it
+    qr_t: Array2<A>,
+    /// The scalar factors of the reflections
+    tau: Vec<A>,
+}
+
+impl<A> Qr<A>
+where
+    A: LinalgFloat,
+{
+    /// Compute the QR decomposition of the matrix `a`.
+    ///
+    /// The decomposition works on a copy of `a` in column-major order, so the copy
+    /// of a matrix that is in column-major (F) order does not need to rearrange the
+    /// elements.
+    pub fn new<S>(a: &ArrayBase<S, Ix2>) -> Self
+    where
+        S: Data<Elem = A>,
+    {
+        let mut qr_t = a.t().as_standard_layout().into_owned();
+        let (tau, _) = householder(qr_t.view_mut(), false);
+        Qr { qr_t, tau }
+    }
+
+    /// Return the factor Q.
+    pub fn q(&self, mode: QrMode) -> Array2<A> {
+        let m = self.qr_t.ncols();
+        let cols = match mode {
+            QrMode::Reduced => self.tau.len(),
+            QrMode::Complete => m,
+        };
+        // Q = H₀ H₁ … applied to the first columns of the identity matrix
+        let mut q_t = Array2::eye(max(cols, m)).slice_move(s![..cols, ..]);
+        for (i, &tau) in self.tau.iter().enumerate().rev() {
+            let v = reflector(self.qr_t.row(i), i);
+            apply_reflector(&v, tau, q_t.slice_mut(s![.., i..]));
+        }
+        q_t.reversed_axes()
+    }
+
+    /// Return the factor R.
+    pub fn r(&self, mode: QrMode) -> Array2<A> {
+        let (n, m) = self.qr_t.dim();
+        let rows = match mode {
+            QrMode::Reduced => self.tau.len(),
+            QrMode::Complete => m,
+        };
+        Array::from_shape_fn((rows, n), |(i, j)| {
+            if i <= j {
+                self.qr_t[[j, i]]
+            } else {
+                A::zero()
+            }
+        })
+    }
+}
+
+/// Compute the QR decomposition A = Q R of the matrix `a`, and return Q and R.
+///
+/// See [`Qr`] for details.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{qr, QrMode};
+///
+/// let a = arr2(&[[3., 1.],
+///                [4., 2.]]);
+/// let (q, r) = qr(&a, QrMode::Complete);
+/// assert_eq!(r[[0, 0]], -5.);
+/// assert_eq!(r[[1, 0]], 0.);
+/// assert!((q.dot(&r) - &a).iter().all(|x: &f64| x.abs() < 1e-12));
+/// ```
+pub fn qr<A, S>(a: &ArrayBase<S, Ix2>, mode: QrMode) -> (Array2<A>, Array2<A>)
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let qr = Qr::new(a);
+    (qr.q(mode), qr.r(mode))
+}
+
+/// The solution of a linear least squares problem, computed by [`lstsq`].
+#[derive(Clone, Debug)]
+pub struct LeastSquares<A: LinalgFloat, D: Dimension> {
+    /// The solution, one for each right hand side.
+    pub solution: Array<A, D>,
+    /// The squared 2-norms of the residuals b − A x, one for each right hand side.
+    ///
+    /// Empty unless A has more rows than columns and full rank, like in NumPy.
+    pub residuals: Array1<A::Real>,
+    /// The effective rank of A.
+    pub rank: usize,
+}
+
+/// Solve the linear least squares problem: find x that minimizes the 2-norm of
+/// b − A x, where `b` is a vector, or do so for each column of `b` if it is a matrix.
+///
+/// The problem is solved with a QR decomposition of `a` with column pivoting, which
+/// also determines the rank of `a`: diagonal elements of R that are smaller than
+/// `max(M, N) * ε` times the largest one are taken to be zero. If `a` does not
+/// have full column rank, the solution is a basic solution, which has at most as
+/// many nonzero elements as the rank, instead of the solution with the least norm.
+///
+/// **Errors** if `b` is not a vector or a matrix with as many rows as `a`.
+///
+/// ```
+/// use ndarray::{arr1, Array, Axis};
+/// use ndarray::linalg::lstsq;
+///
+/// // fit a line y = c₀ + c₁ x to some points
+/// let x = arr1(&[0., 1., 2., 3.]);
+/// let y = arr1(&[1., 3., 4., 6.]);
+/// let a = ndarray::stack![Axis(1), Array::ones(4), x];
+/// let fit = lstsq(&a, &y).unwrap();
+/// assert_eq!(fit.rank, 2);
+/// assert!((&fit.solution - &arr1(&[1.1, 1.6])).iter().all(|e: &f64| e.abs() < 1e-12));
+/// assert!((fit.residuals[0] - 0.2f64).abs() < 1e-12);
+/// ```
+pub fn lstsq<A, S1, S2, D>(
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, D>,
+) -> Result<LeastSquares<A, D>, LinalgError>
+where
+    A: LinalgFloat,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    let (m, n) = a.dim();
+    let b2 = match b.ndim() {
+        1 => b.view().into_dimensionality::<Ix1>()?.insert_axis(Axis(1)),
+        _ => b.view().into_dimensionality::<Ix2>()?,
+    };
+    if b2.nrows() != m {
+        let mut expected = b.shape().to_vec();
+        expected[0] = m;
+        return Err(crate::error::from_kind(crate::ErrorKind::IncompatibleShape)
+            .with_operation("lstsq")
+            .with_shapes(&expected, b.shape())
+            .into());
+    }
+    let nrhs = b2.ncols();
+
+    // A P = Q R, with Q and R stored transposed
+    let mut qr_t = a.t().as_standard_layout().into_owned();
+    let (tau, perm) = householder(qr_t.view_mut(), true);
+    let r_diag = qr_t.diag();
+    let tol = r_diag.get(0).map_or(Zero::zero(), |r| r.abs())
+        * A::Real::epsilon()
+        * <A::Real as NumCast>::from(max(m, n)).unwrap();
+    let rank = r_diag.iter().take_while(|r| r.abs() > tol).count();
+
+    // c = Qᴴ b, stored transposed
+    let mut c_t = b2.t().as_standard_layout().into_owned();
+    for (i, &tau) in tau.iter().enumerate() {
+        let v = reflector(qr_t.row(i), i);
+        apply_reflector(&v, tau.conj(), c_t.slice_mut(s![.., i..]));
+    }
+    // solve R₁₁ y = c₁ in the first `rank` rows
+    triangular_solve_mat(
+        Triangle::Upper,
+        false,
+        &qr_t.slice(s![..rank, ..rank]).t(),
+        c_t.slice_mut(s![.., ..rank]).reversed_axes(),
+    );
+    let mut x = Array2::zeros((n, nrhs));
+    for (i, &p) in perm.iter().take(rank).enumerate() {
+        x.row_mut(p).assign(&c_t.column(i));
+    }
+    let residuals = if rank == n && m > n {
+        c_t.slice(s![.., n..])
+            .rows()
+            .into_iter()
+            .map(|r| r.iter().fold(A::Real::zero(), |acc, x| acc + x.abs_sqr()))
+            .collect()
+    } else {
+        Array1::zeros(0)
+    };
+    let mut dim = b.raw_dim();
+    dim[0] = n;
+    Ok(LeastSquares {
+        solution: x.into_shape(dim)?,
+        residuals,
+        rank,
+    })
+}
+
+/// Compute the QR decomposition of the matrix A, whose transpose is `a_t`, with
+/// Householder reflections, optionally pivoting the columns of A by their norm.
+///
+/// `a_t` is overwritten with R (transposed) in its lower triangle, and the
+/// reflection vectors v (without their leading one) in the rest. Return the scalar
+/// factors τ of the reflections H = I − τ v vᴴ, and the column permutation.
+fn householder<A>(mut a_t: ArrayViewMut2<'_, A>, pivot: bool) -> (Vec<A>, Vec<usize>)
+where
+    A: LinalgFloat,
+{
+    let (n, m) = a_t.dim();
+    let k = min(m, n);
+    let mut tau = Vec::with_capacity(k);
+    let mut perm: Vec<usize> = (0..n).collect();
+    for i in 0..k {
+        if pivot {
+            // the column with the largest norm in the rows that are left
+            let norm_sqr = |j: usize| {
+                a_t.slice(s![j, i..])
+                    .iter()
+                    .fold(A::Real::zero(), |acc, x| acc + x.abs_sqr())
+            };
+            let mut p = i;
+            let mut max_norm = norm_sqr(i);
+            for j in i + 1..n {
+                let norm = norm_sqr(j);
+                if norm > max_norm {
+                    p = j;
+                    max_norm = norm;
+                }
+            }
+            if p != i {
+                swap_rows(&mut a_t, i, p);
+                perm.swap(i, p);
+            }
+        }
+        let tau_i = make_reflector(a_t.slice_mut(s![i, i..]));
+        tau.push(tau_i);
+        if !tau_i.is_zero() {
+            let v = reflector(a_t.row(i), i);
+            apply_reflector(&v, tau_i.conj(), a_t.slice_mut(s![i + 1.., i..]));
+        }
+    }
+    (tau, perm)
+}
@@ -0,0 +1,118 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use std::ops::Neg;
+
+use num_complex::Complex;
+
+use crate::{LinalgScalar, NdFloat, ScalarOperand};
+
+/// Elements of the matrix decompositions: the real floating point types `f32` and
+/// `f64`, and the complex types `Complex<f32>` and `Complex<f64>`.
+///
+/// This trait can only be implemented by ndarray.
+pub trait LinalgFloat:
+    LinalgScalar + Neg<Output = Self> + ScalarOperand + fmt::Debug + Send + Sync
+{
+    /// The real type: `Self` for the real types, and the type of the real and
+    /// imaginary parts for the complex types.
+    type Real: NdFloat + LinalgFloat<Real = Self::Real>;
+
+    /// Return the number with real part `re` and no imaginary part.
+    fn from_real(re: Self::Real) -> Self;
+
+    /// Return the real part.
+    fn re(self) -> Self::Real;
+
+    /// Return the complex conjugate (the number itself for the real types).
+    fn conj(self) -> Self;
+
+    /// Return the absolute value (the modulus for the complex types).
+    fn abs(self) -> Self::Real;
+
+    /// Return the square of the absolute value.
+    fn abs_sqr(self) -> Self::Real;
+
+    private_decl! {}
+}
+
+macro_rules! impl_real {
+    ($t:ty) => {
+        impl LinalgFloat for $t {
+            type Real = $t;
+
+            #[inline]
+            fn from_real(re: $t) -> Self {
+                re
+            }
+
+            #[inline]
+            fn re(self) -> $t {
+                self
+            }
+
+            #[inline]
+            fn conj(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn abs(self) -> $t {
+                <$t>::abs(self)
+            }
+
+            #[inline]
+            fn abs_sqr(self) -> $t {
+                self * self
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+macro_rules! impl_complex {
+    ($t:ty) => {
+        impl LinalgFloat for Complex<$t> {
+            type Real = $t;
+
+            #[inline]
+            fn from_real(re: $t) -> Self {
+                Complex::new(re, 0.)
+            }
+
+            #[inline]
+            fn re(self) -> $t {
+                self.re
+            }
+
+            #[inline]
+            fn conj(self) -> Self {
+                Complex::conj(&self)
+            }
+
+            #[inline]
+            fn abs(self) -> $t {
+                self.re.hypot(self.im)
+            }
+
+            #[inline]
+            fn abs_sqr(self) -> $t {
+                self.norm_sqr()
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+impl_real!(f32);
+impl_real!(f64);
+impl_complex!(f32);
+impl_complex!(f64);
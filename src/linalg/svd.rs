@@ -0,0 +1,350 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::{max, Ordering};
+
+use alloc::vec::Vec;
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+use super::{LinalgError, LinalgFloat, Qr, QrMode};
+
+/// The maximum number of sweeps of Jacobi rotations.
+const MAX_SWEEPS: usize = 60;
+
+/// The singular vectors that [`svd`] computes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SvdMode {
+    /// For a *M* × *N* matrix: U is *M* × *M* and Vᴴ is *N* × *N*.
+    Full,
+    /// For a *M* × *N* matrix with *K* = min(*M*, *N*): U is *M* × *K* and Vᴴ is
+    /// *K* × *N*.
+    Thin,
+    /// Compute only the singular values.
+    ValuesOnly,
+}
+
+/// Compute the singular value decomposition A = U Σ Vᴴ of the matrix `a`.
+///
+/// Return U, the singular values (the diagonal of Σ) in descending order, and Vᴴ;
+/// the singular vectors are `None` for [`SvdMode::ValuesOnly`]. U and V have
+/// orthonormal columns (they are unitary in the full mode).
+///
+/// The decomposition is computed with one-sided Jacobi rotations, after a QR
+/// decomposition if `a` is not square.
+///
+/// **Errors** if the Jacobi iteration does not converge (which does not happen in
+/// practice unless `a` contains NaN or infinity).
+///
+/// ```
+/// use ndarray::{arr1, arr2, Array2};
+/// use ndarray::linalg::{svd, SvdMode};
+///
+/// let a = arr2(&[[3., 0.],
+///                [4., 5.]]);
+/// let (u, s, vt) = svd(&a, SvdMode::Full).unwrap();
+/// let (u, vt) = (u.unwrap(), vt.unwrap());
+/// assert!((&s - &arr1(&[45f64.sqrt(), 5f64.sqrt()])).iter().all(|x: &f64| x.abs() < 1e-12));
+/// let usv = u.dot(&Array2::from_diag(&s)).dot(&vt);
+/// assert!((usv - &a).iter().all(|x: &f64| x.abs() < 1e-12));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn svd<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    mode: SvdMode,
+) -> Result<(Option<Array2<A>>, Array1<A::Real>, Option<Array2<A>>), LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    if m >= n {
+        let (u, s, v) = svd_tall(a.view(), mode)?;
+        Ok((u, s, v.map(|v| v.t().mapv(A::conj))))
+    } else {
+        // A = (Aᴴ)ᴴ = V Σ Uᴴ
+        let (u, s, v) = svd_tall(a.t().mapv(A::conj).view(), mode)?;
+        Ok((v, s, u.map(|u| u.t().mapv(A::conj))))
+    }
+}
+
+/// Compute the pseudo-inverse (Moore-Penrose inverse) of the matrix `a`.
+///
+/// Singular values that are not greater than `tol` are taken to be zero; the
+/// default is the same as for [`matrix_rank`].
+///
+/// **Errors** if the singular value decomposition does not converge.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::pinv;
+///
+/// let a = arr2(&[[1., 2.],
+///                [2., 4.],
+///                [0., 0.]]);
+/// let a_pinv = pinv(&a, None).unwrap();
+/// assert_eq!(a_pinv.dim(), (2, 3));
+/// assert!((a.dot(&a_pinv).dot(&a) - &a).iter().all(|x: &f64| x.abs() < 1e-12));
+/// ```
+pub fn pinv<A, S>(a: &ArrayBase<S, Ix2>, tol: Option<A::Real>) -> Result<Array2<A>, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (u, s, vt) = svd(a, SvdMode::Thin)?;
+    let (u, vt) = (u.unwrap(), vt.unwrap());
+    let rank = rank_of(&s, a.dim(), tol);
+    // A⁺ = V Σ⁺ Uᴴ = (U Σ⁺ Vᴴ)ᴴ, using only the first `rank` singular values
+    let mut u = u.slice_move(s![.., ..rank]);
+    Zip::from(u.columns_mut())
+        .and(s.slice(s![..rank]))
+        .for_each(|mut col, &s| col.map_inplace(|x| *x = *x * A::from_real(s.recip())));
+    Ok(u.dot(&vt.slice(s![..rank, ..])).t().mapv(A::conj))
+}
+
+/// Return the rank of the matrix `a`: the number of its singular values that are
+/// greater than `tol`.
+///
+/// The default `tol` is σ₁ ε max(*M*, *N*) for a *M* × *N* matrix, where σ₁ is the
+/// largest singular value and ε the machine epsilon, like in NumPy.
+///
+/// **Errors** if the singular value decomposition does not converge.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::matrix_rank;
+///
+/// let a = arr2(&[[1., 2., 3.],
+///                [2., 4., 6.]]);
+/// assert_eq!(matrix_rank(&a, None).unwrap(), 1);
+/// assert_eq!(matrix_rank(&a.t(), Some(100.)).unwrap(), 0);
+/// ```
+pub fn matrix_rank<A, S>(a: &ArrayBase<S, Ix2>, tol: Option<A::Real>) -> Result<usize, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (_, s, _) = svd(a, SvdMode::ValuesOnly)?;
+    Ok(rank_of(&s, a.dim(), tol))
+}
+
+/// Return the condition number of the matrix `a` in the spectral norm: the ratio of
+/// its largest and smallest singular values.
+///
+/// The condition number is infinite if `a` is singular, and zero if it is empty.
+///
+/// **Errors** if the singular value decomposition does not converge.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::cond;
+///
+/// let a = arr2(&[[2., 0.],
+///                [0., 0.5]]);
+/// assert_eq!(cond(&a).unwrap(), 4.);
+/// ```
+pub fn cond<A, S>(a: &ArrayBase<S, Ix2>) -> Result<A::Real, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (_, s, _) = svd(a, SvdMode::ValuesOnly)?;
+    match (s.first(), s.last()) {
+        (Some(&max), Some(&min)) => Ok(max / min),
+        _ => Ok(A::Real::zero()),
+    }
+}
+
+/// Return the spectral norm (the matrix 2-norm) of the matrix `a`: its largest
+/// singular value.
+///
+/// **Errors** if the singular value decomposition does not converge.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::spectral_norm;
+///
+/// let a = arr2(&[[0., 2.],
+///                [-3., 0.]]);
+/// assert_eq!(spectral_norm(&a).unwrap(), 3.);
+/// ```
+pub fn spectral_norm<A, S>(a: &ArrayBase<S, Ix2>) -> Result<A::Real, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (_, s, _) = svd(a, SvdMode::ValuesOnly)?;
+    Ok(s.first().cloned().unwrap_or_else(Zero::zero))
+}
+
+/// Return the nuclear norm (the trace norm) of the matrix `a`: the sum of its
+/// singular values.
+///
+/// **Errors** if the singular value decomposition does not converge.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::nuclear_norm;
+///
+/// let a = arr2(&[[0., 2.],
+///                [-3., 0.]]);
+/// assert_eq!(nuclear_norm(&a).unwrap(), 5.);
+/// ```
+pub fn nuclear_norm<A, S>(a: &ArrayBase<S, Ix2>) -> Result<A::Real, LinalgError>
+where
+    A: LinalgFloat,
+    S: Data<Elem = A>,
+{
+    let (_, s, _) = svd(a, SvdMode::ValuesOnly)?;
+    Ok(s.sum())
+}
+
+/// Return the number of singular values `s` (in descending order) of a matrix of
+/// dimension `dim` that are greater than `tol`, or the default tolerance.
+fn rank_of<R: Float>(s: &Array1<R>, dim: (usize, usize), tol: Option<R>) -> usize {
+    let tol = tol.unwrap_or_else(|| {
+        let max_dim: R = NumCast::from(max(dim.0, dim.1)).unwrap();
+        s.first().map_or(R::zero(), |&s| s * R::epsilon() * max_dim)
+    });
+    s.iter().take_while(|&&s| s > tol).count()
+}
+
+/// Compute the singular value decomposition of the *M* × *N* matrix `a`, where
+/// *M* ≥ *N*, and return U, the singular values and V (not Vᴴ).
+#[allow(clippy::type_complexity)]
+fn svd_tall<A>(
+    a: ArrayView2<'_, A>,
+    mode: SvdMode,
+) -> Result<(Option<Array2<A>>, Array1<A::Real>, Option<Array2<A>>), LinalgError>
+where
+    A: LinalgFloat,
+{
+    let (m, n) = a.dim();
+    let vectors = mode != SvdMode::ValuesOnly;
+    // A = Q R, and the decomposition of R gives the one of A with U = Q U_R
+    let qr = if m > n { Some(Qr::new(&a)) } else { None };
+    // the columns of the matrix that is orthogonalized, stored as rows
+    let mut w_t = match &qr {
+        Some(qr) => qr.r(QrMode::Reduced).reversed_axes(),
+        None => a.t().to_owned(),
+    };
+    let mut v_t = if vectors { Some(Array2::eye(n)) } else { None };
+    one_sided_jacobi(w_t.view_mut(), v_t.as_mut().map(|v_t| v_t.view_mut()))?;
+
+    let norms: Vec<A::Real> = w_t
+        .rows()
+        .into_iter()
+        .map(|w| w.iter().fold(A::Real::zero(), |acc, x| acc + x.abs_sqr()).sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+    let s: Array1<_> = order.iter().map(|&i| norms[i]).collect();
+    let v_t = match v_t {
+        Some(v_t) => v_t,
+        None => return Ok((None, s, None)),
+    };
+
+    // the left singular vectors are the normalized columns; the ones for zero
+    // singular values are found by `complete_basis`
+    let nonzero = s.iter().take_while(|s| !s.is_zero()).count();
+    let mut u = w_t.select(Axis(0), &order[..nonzero]).reversed_axes();
+    Zip::from(u.columns_mut())
+        .and(s.slice(s![..nonzero]))
+        .for_each(|mut col, &s| col.map_inplace(|x| *x = *x * A::from_real(s.recip())));
+    if let Some(qr) = &qr {
+        u = qr.q(QrMode::Reduced).dot(&u);
+    }
+    let cols = match mode {
+        SvdMode::Full => m,
+        _ => n,
+    };
+    if nonzero < cols {
+        u = complete_basis(u, cols);
+    }
+    Ok((Some(u), s, Some(v_t.select(Axis(0), &order).reversed_axes())))
+}
+
+/// Orthogonalize the rows of `w_t` with Jacobi rotations, which are also applied to
+/// the rows of `v_t` if present.
+fn one_sided_jacobi<A>(
+    mut w_t: ArrayViewMut2<'_, A>,
+    mut v_t: Option<ArrayViewMut2<'_, A>>,
+) -> Result<(), LinalgError>
+where
+    A: LinalgFloat,
+{
+    let (n, len) = w_t.dim();
+    let two = A::Real::one() + A::Real::one();
+    // the rounding error of the inner products is proportional to the length
+    let tol = A::Real::epsilon() * <A::Real as NumCast>::from(max(len, 1)).unwrap();
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (w_p, w_q) = w_t.multi_slice_mut((s![p, ..], s![q, ..]));
+                let (mut alpha, mut beta, mut gamma) = (A::Real::zero(), A::Real::zero(), A::zero());
+                Zip::from(&w_p).and(&w_q).for_each(|&x, &y| {
+                    alpha += x.abs_sqr();
+                    beta += y.abs_sqr();
+                    gamma = gamma + x.conj() * y;
+                });
+                let g = gamma.abs();
+                if g.is_zero() || g <= tol * alpha.sqrt() * beta.sqrt() {
+                    continue;
+                }
+                rotated = true;
+                // the rotation that makes the columns p and q orthogonal, after
+                // rotating the phase of column q so that their inner product is real
+                let zeta = (beta - alpha) / (two * g);
+                let t = A::Real::one().copysign(zeta)
+                    / (Float::abs(zeta) + Float::hypot(zeta, A::Real::one()));
+                let c = (A::Real::one() + t * t).sqrt().recip();
+                let phase = gamma * A::from_real(g.recip());
+                rotate(w_p, w_q, c, c * t, phase);
+                if let Some(v_t) = &mut v_t {
+                    let (v_p, v_q) = v_t.multi_slice_mut((s![p, ..], s![q, ..]));
+                    rotate(v_p, v_q, c, c * t, phase);
+                }
+            }
+        }
+        if !rotated {
+            return Ok(());
+        }
+    }
+    Err(LinalgError::NoConvergence)
+}
+
+/// Compute x ← c x − s e^(−iφ) y and y ← s e^(iφ) x + c y, where `phase` is e^(iφ).
+fn rotate<A>(x: ArrayViewMut1<'_, A>, y: ArrayViewMut1<'_, A>, c: A::Real, s: A::Real, phase: A)
+where
+    A: LinalgFloat,
+{
+    let (c, s_phase) = (A::from_real(c), phase * A::from_real(s));
+    Zip::from(x).and(y).for_each(|x, y| {
+        let (x0, y0) = (*x, *y);
+        *x = c * x0 - s_phase.conj() * y0;
+        *y = s_phase * x0 + c * y0;
+    });
+}
+
+/// Extend the orthonormal columns of `u` to `cols` orthonormal columns.
+fn complete_basis<A>(u: Array2<A>, cols: usize) -> Array2<A>
+where
+    A: LinalgFloat,
+{
+    // U = Q R where R is both triangular and unitary, so it is diagonal
+    let qr = Qr::new(&u);
+    let r = qr.r(QrMode::Reduced);
+    let mut q = qr.q(QrMode::Complete).slice_move(s![.., ..cols]);
+    Zip::from(q.slice_mut(s![.., ..u.ncols()]).columns_mut())
+        .and(r.diag())
+        .for_each(|mut col, &r| col.map_inplace(|x| *x = *x * r));
+    q
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+
+use num_traits::{Float, Zero};
+
+use crate::error::{from_kind, ErrorKind};
+use crate::imp_prelude::*;
+use crate::Zip;
+
+use super::{general_outer_add, LinalgError, LinalgFloat};
+
+/// Solve a system of `n` equations with the right hand side `b`, which is a vector
+/// or a matrix with a right hand side in each column.
+///
+/// `solve` is called with a copy of `b`, viewed as a matrix, and overwrites it with
+/// the solution.
+///
+/// **Errors** if `b` is not a vector or matrix with `n` rows (the error is about
+/// `operation`), or if `solve` fails.
+pub(super) fn solve_rhs<A, S, D, F>(
+    n: usize,
+    b: &ArrayBase<S, D>,
+    operation: &'static str,
+    solve: F,
+) -> Result<Array<A, D>, LinalgError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+    F: FnOnce(ArrayViewMut2<'_, A>) -> Result<(), LinalgError>,
+{
+    let cols = match *b.shape() {
+        [rows] if rows == n => 1,
+        [rows, cols] if rows == n => cols,
+        _ => {
+            let mut expected = b.shape().to_vec();
+            match expected.first_mut() {
+                Some(rows) if b.ndim() <= 2 => *rows = n,
+                _ => expected = vec![n],
+            }
+            return Err(from_kind(ErrorKind::IncompatibleShape)
+                .with_operation(operation)
+                .with_shapes(&expected, b.shape())
+                .into());
+        }
+    };
+    let mut x = b.as_standard_layout().into_owned();
+    solve(x.view_mut().into_shape((n, cols))?)?;
+    Ok(x)
+}
+
+/// Swap rows `i` and `j` of `a`, which must be distinct.
+pub(super) fn swap_rows<A>(a: &mut ArrayViewMut2<'_, A>, i: usize, j: usize) {
+    let (mut row_i, mut row_j) = a.multi_slice_mut((s![i, ..], s![j, ..]));
+    Zip::from(&mut row_i)
+        .and(&mut row_j)
+        .for_each(std::mem::swap);
+}
+
+/// Compute the reflection H = I − τ v vᴴ, with v₀ = 1, such that Hᴴ x is zero
+/// except for its first element, which is real.
+///
+/// `x` is overwritten with that element, followed by v without its leading one.
+/// Return τ.
+pub(super) fn make_reflector<A>(mut x: ArrayViewMut1<'_, A>) -> A
+where
+    A: LinalgFloat,
+{
+    let alpha = x[0];
+    let tail_norm_sqr = x
+        .slice(s![1..])
+        .iter()
+        .fold(A::Real::zero(), |acc, x| acc + x.abs_sqr());
+    if tail_norm_sqr.is_zero() && (alpha - A::from_real(alpha.re())).is_zero() {
+        return A::zero();
+    }
+    let norm = (alpha.abs_sqr() + tail_norm_sqr).sqrt();
+    let beta = if alpha.re() >= A::Real::zero() { -norm } else { norm };
+    let beta = A::from_real(beta);
+    let scale = A::one() / (alpha - beta);
+    x.slice_mut(s![1..]).map_inplace(|x| *x = *x * scale);
+    x[0] = beta;
+    (beta - alpha) / beta
+}
+
+/// Return the vector v of a reflection, as computed by `make_reflector` in the
+/// elements of `x` from index `i` on.
+pub(super) fn reflector<A>(x: ArrayView1<'_, A>, i: usize) -> Array1<A>
+where
+    A: LinalgFloat,
+{
+    let mut v = x.slice(s![i..]).to_owned();
+    v[0] = A::one();
+    v
+}
+
+/// Apply the reflection I − τ v vᴴ from the left to the matrix C whose transpose
+/// is `c_t`.
+pub(super) fn apply_reflector<A>(v: &Array1<A>, tau: A, mut c_t: ArrayViewMut2<'_, A>)
+where
+    A: LinalgFloat,
+{
+    // w = Cᴴ v, conjugated: wᵢ = vᴴ cᵢ
+    let w = c_t.dot(&v.mapv(A::conj));
+    general_outer_add(-tau, &w, v, &mut c_t);
+}
@@ -1,6 +1,12 @@
-use crate::{Array, ArrayBase, DataMut, Dimension, IntoNdProducer, NdProducer, Zip};
+use crate::{Array, ArrayBase, ArrayView, ArrayView1, Axis, Data, DataMut, Dimension, IntoNdProducer,
+            NdProducer, RemoveAxis, Zip};
 use crate::AssignElem;
 
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::{FromPrimitive, Zero};
+use std::ops::{Add, Div};
+
 use crate::parallel::prelude::*;
 use crate::parallel::par::ParallelSplits;
 use super::send_producer::SendProducer;
@@ -45,6 +51,190 @@ where
     }
 }
 
+/// # Parallel reductions
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: Sync,
+{
+    /// Parallel version of `map_axis`.
+    ///
+    /// Reduce the values along an axis into just one value, producing a new
+    /// array with one less dimension. The lanes along `axis` are mapped in parallel.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_map_axis<'a, B, F>(&'a self, axis: Axis, mapping: F) -> Array<B, D::Smaller>
+    where
+        D: RemoveAxis,
+        F: Fn(ArrayView1<'a, A>) -> B + Sync + Send,
+        A: 'a,
+        B: Send,
+    {
+        Zip::from(self.lanes(axis)).par_map_collect(mapping)
+    }
+
+    /// Parallel version of `fold_axis`.
+    ///
+    /// Fold the elements of each lane along `axis`, in order, with the `fold`
+    /// function and initial value `init`. The lanes are folded in parallel.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_fold_axis<B, F>(&self, axis: Axis, init: B, fold: F) -> Array<B, D::Smaller>
+    where
+        D: RemoveAxis,
+        F: Fn(&B, &A) -> B + Sync + Send,
+        B: Clone + Sync + Send,
+    {
+        self.par_map_axis(axis, |lane| lane.iter().fold(init.clone(), |acc, x| fold(&acc, x)))
+    }
+
+    /// Parallel version of `sum`.
+    ///
+    /// The array is split in halves recursively until the parts are small enough
+    /// to be summed sequentially. The split points depend only on the shape and
+    /// memory layout of the array, so the result is deterministic, also for floating
+    /// point elements, but it can differ slightly from the result of `.sum()`.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::linspace(0., 1.0f64, 1_000_000);
+    /// assert_eq!(a.par_sum(), a.par_sum());
+    /// assert!((a.par_sum() - 500_000.).abs() < 1e-6);
+    /// ```
+    pub fn par_sum(&self) -> A
+    where
+        A: Clone + Add<Output = A> + Zero + Send,
+    {
+        par_sum_view(self.view())
+    }
+
+    /// Parallel version of `sum_axis`.
+    ///
+    /// The array is split into parts along another axis, which are summed in
+    /// parallel. Each element of the result is summed in the same order as by
+    /// `sum_axis`, so the result is the same. One-dimensional arrays are summed
+    /// sequentially.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_sum_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Clone + Zero + Add<Output = A> + Send,
+        D: RemoveAxis,
+    {
+        let stride = self.strides()[axis.index()];
+        if self.ndim() == 2 && stride == 1 {
+            // contiguous along the axis we are summing
+            return self.par_map_axis(axis, |lane| lane.sum());
+        } else if self.ndim() == 1 {
+            // a single lane, with no other axis to split
+            return self.sum_axis(axis);
+        }
+        // split the longest of the other axes into parts, and in each part add the
+        // subviews along `axis` one by one
+        let split = (0..self.ndim())
+            .filter(|&i| i != axis.index())
+            .max_by_key(|&i| self.len_of(Axis(i)))
+            .unwrap();
+        let res_split = split - (split > axis.index()) as usize;
+        let part_len = self.len_of(Axis(split)) / (4 * rayon::current_num_threads()) + 1;
+        let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+        res.axis_chunks_iter_mut(Axis(res_split), part_len)
+            .into_par_iter()
+            .zip(self.axis_chunks_iter(Axis(split), part_len))
+            .for_each(|(mut res, part)| {
+                for view in part.axis_iter(axis) {
+                    Zip::from(&mut res)
+                        .and(&view)
+                        .for_each(|r: &mut A, x| *r = r.clone() + x.clone());
+                }
+            });
+        res
+    }
+
+    /// Parallel version of `mean_axis`.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()`
+    /// fails for the axis length.
+    pub fn par_mean_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A> + Send,
+        D: RemoveAxis,
+    {
+        let axis_length = self.len_of(axis);
+        if axis_length == 0 {
+            None
+        } else {
+            let axis_length =
+                A::from_usize(axis_length).expect("Converting axis length to `A` must not fail.");
+            let mut sum = self.par_sum_axis(axis);
+            sum.par_mapv_inplace(|x| x / axis_length.clone());
+            Some(sum)
+        }
+    }
+
+    /// Parallel version of `var_axis`.
+    ///
+    /// The variance of each lane along `axis` is computed with the Welford one-pass
+    /// algorithm, and the lanes are processed in parallel.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`, if `axis`
+    /// is out of bounds, or if `A::from_usize()` fails for any any of the
+    /// numbers in the range `0..=n`.
+    #[cfg(feature = "std")]
+    pub fn par_var_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where
+        A: Float + FromPrimitive + Send,
+        D: RemoveAxis,
+    {
+        let zero = A::from_usize(0).expect("Converting 0 to `A` must not fail.");
+        let n = A::from_usize(self.len_of(axis)).expect("Converting length to `A` must not fail.");
+        assert!(
+            !(ddof < zero || ddof > n),
+            "`ddof` must not be less than zero or greater than the length of \
+             the axis",
+        );
+        let dof = n - ddof;
+        self.par_map_axis(axis, |lane| {
+            let mut mean = zero;
+            let mut sum_sq = zero;
+            for (i, &x) in lane.iter().enumerate() {
+                let count = A::from_usize(i + 1).expect("Converting index to `A` must not fail.");
+                let delta = x - mean;
+                mean = mean + delta / count;
+                sum_sq = (x - mean).mul_add(delta, sum_sq);
+            }
+            sum_sq / dof
+        })
+    }
+}
+
+/// Array length below which `par_sum` sums sequentially
+///
+/// This constant determines the splitting tree and therefore the rounding of
+/// floating point sums; changing it changes the results of `par_sum`.
+const PAR_SUM_SEQUENTIAL_LEN: usize = 1 << 14;
+
+fn par_sum_view<A, D>(view: ArrayView<'_, A, D>) -> A
+where
+    A: Clone + Add<Output = A> + Zero + Send + Sync,
+    D: Dimension,
+{
+    if view.len() <= PAR_SUM_SEQUENTIAL_LEN {
+        return view.sum();
+    }
+    let axis = view.max_stride_axis();
+    let mid = view.len_of(axis) / 2;
+    let (a, b) = view.split_at(axis, mid);
+    let (sum_a, sum_b) = rayon::join(move || par_sum_view(a), move || par_sum_view(b));
+    sum_a + sum_b
+}
+
 // Zip
 
 const COLLECT_MAX_SPLITS: usize = 10;
@@ -84,6 +274,69 @@ macro_rules! zip_impl {
                 self.into_par_iter().for_each(move |($($p,)*)| function($($p),*))
             }
 
+            /// Parallel version of `fold`.
+            ///
+            /// The zip is split into parts that are folded in parallel, each part starting
+            /// from `identity()`; the partial results are then combined with `combine`.
+            /// The parts are folded in the same way as in `fold`, elements are
+            /// visited in arbitrary order.
+            ///
+            /// Requires crate feature `rayon`.
+            ///
+            /// ```
+            /// use ndarray::{Array, Zip};
+            ///
+            /// let a = Array::from_shape_fn((64, 64), |(i, j)| (i + j) as f64);
+            /// let b = Array::from_elem((64, 64), 0.5);
+            ///
+            /// // dot product and count of positive products, in one pass
+            /// let (dot, count) = Zip::from(&a).and(&b).par_fold(
+            ///     || (0., 0),
+            ///     |(dot, count), &x, &y| (dot + x * y, count + (x * y > 0.) as usize),
+            ///     |(d1, c1), (d2, c2)| (d1 + d2, c1 + c2));
+            /// assert_eq!(dot, (&a * &b).sum());
+            /// assert_eq!(count, 64 * 64 - 1);
+            /// ```
+            pub fn par_fold<T, ID, F, C>(self, identity: ID, fold: F, combine: C) -> T
+                where T: Send,
+                      ID: Fn() -> T + Sync + Send,
+                      F: Fn(T, $($p::Item),*) -> T + Sync + Send,
+                      C: Fn(T, T) -> T + Sync + Send,
+            {
+                self.into_par_iter()
+                    .fold(&identity, move |acc, ($($p,)*)| fold(acc, $($p),*))
+                    .reduce(&identity, combine)
+            }
+
+            /// Parallel version of `map_reduce`.
+            ///
+            /// Map each element tuple with `map` and combine the results with `reduce`.
+            /// `identity()` must be the neutral element of `reduce`, since it may be used
+            /// any number of times, and `reduce` should be associative since the order
+            /// of combining the results is not specified.
+            ///
+            /// Requires crate feature `rayon`.
+            ///
+            /// ```
+            /// use ndarray::{Array, Zip};
+            ///
+            /// let a = Array::linspace(0., 1., 1000);
+            /// let b = Array::linspace(1., 0., 1000);
+            /// let max_diff = Zip::from(&a).and(&b)
+            ///     .par_map_reduce(|&x, &y| f64::abs(x - y), || 0., f64::max);
+            /// assert_eq!(max_diff, 1.);
+            /// ```
+            pub fn par_map_reduce<R, M, ID, RF>(self, map: M, identity: ID, reduce: RF) -> R
+                where R: Send,
+                      M: Fn($($p::Item),*) -> R + Sync + Send,
+                      ID: Fn() -> R + Sync + Send,
+                      RF: Fn(R, R) -> R + Sync + Send,
+            {
+                self.into_par_iter()
+                    .map(move |($($p,)*)| map($($p),*))
+                    .reduce(identity, reduce)
+            }
+
             expand_if!(@bool [$notlast]
 
             /// Map and collect the results into a new array, which has the same size as the
@@ -189,5 +442,11 @@ zip_impl! {
     [true P1 P2 P3],
     [true P1 P2 P3 P4],
     [true P1 P2 P3 P4 P5],
-    [false P1 P2 P3 P4 P5 P6],
+    [true P1 P2 P3 P4 P5 P6],
+    [true P1 P2 P3 P4 P5 P6 P7],
+    [true P1 P2 P3 P4 P5 P6 P7 P8],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9 P10],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11],
+    [false P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11 P12],
 }
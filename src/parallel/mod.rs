@@ -15,15 +15,23 @@
 //! - [`ArrayViewMut`] `.into_par_iter()`
 //! - [`AxisIter`], [`AxisIterMut`] `.into_par_iter()`
 //! - [`AxisChunksIter`], [`AxisChunksIterMut`] `.into_par_iter()`
+//! - [`Windows`], [`ExactChunks`], [`ExactChunksMut`] `.into_par_iter()`
+//! - [`Chunks`], [`ChunksMut`] `.into_par_iter()`
+//! - [`Lanes`], [`LanesMut`] `.into_par_iter()`
 //! - [`Zip`] `.into_par_iter()`
 //!
 //! The following other parallelized methods exist:
 //!
 //! - [`ArrayBase::par_map_inplace()`]
 //! - [`ArrayBase::par_mapv_inplace()`]
+//! - [`ArrayBase::par_map_axis()`], [`ArrayBase::par_fold_axis()`]
+//! - [`ArrayBase::par_sum()`], [`ArrayBase::par_sum_axis()`], [`ArrayBase::par_mean_axis()`],
+//!   [`ArrayBase::par_var_axis()`]
 //! - [`Zip::par_for_each()`] (all arities)
 //! - [`Zip::par_map_collect()`] (all arities)
 //! - [`Zip::par_map_assign_into()`] (all arities)
+//! - [`Zip::par_fold()`] (all arities)
+//! - [`Zip::par_map_reduce()`] (all arities)
 //!
 //! Note that you can use the parallel iterator for [Zip] to access all other
 //! rayon parallel iterator methods.
@@ -96,6 +104,33 @@
 //! }
 //! ```
 //!
+//! ## Windows and exact chunks
+//!
+//! Use the parallel `.windows()` for a stencil computation, and the parallel
+//! `.exact_chunks_mut()` to process blocks of an array.
+//!
+//! ```
+//! use ndarray::{Array2, Zip};
+//! use ndarray::parallel::prelude::*;
+//!
+//! let a = Array2::from_shape_fn((64, 64), |(i, j)| (i * j) as f64);
+//!
+//! // the windows are also producers for Zip, so the results can be collected
+//! let mean = Zip::from(a.windows((3, 3))).par_map_collect(|w| w.mean().unwrap());
+//! assert_eq!(mean.dim(), (62, 62));
+//! let max = a.windows((3, 3))
+//!     .into_par_iter()
+//!     .map(|w| w.sum())
+//!     .reduce(|| 0., f64::max);
+//! assert_eq!(max, 9. * 62. * 62.);
+//!
+//! let mut b = Array2::<f64>::zeros((64, 64));
+//! b.exact_chunks_mut((8, 8))
+//!     .into_par_iter()
+//!     .for_each(|mut block| block.fill(1.));
+//! assert_eq!(b.sum(), 64. * 64.);
+//! ```
+//!
 //! ## Zip
 //!
 //! Use zip for lock step function application across several arrays
@@ -136,6 +171,13 @@ use crate::iter::{
     AxisIterMut,
     AxisChunksIter,
     AxisChunksIterMut,
+    Chunks,
+    ChunksMut,
+    ExactChunks,
+    ExactChunksMut,
+    Lanes,
+    LanesMut,
+    Windows,
 };
 
 /// Into- traits for creating parallelized iterators and/or using [`par_azip!`]
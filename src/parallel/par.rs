@@ -13,6 +13,7 @@ use crate::iter::AxisChunksIter;
 use crate::iter::AxisChunksIterMut;
 use crate::iter::AxisIter;
 use crate::iter::AxisIterMut;
+use crate::iter::{Chunks, ChunksMut, ExactChunks, ExactChunksMut, Lanes, LanesMut, Windows};
 use crate::Dimension;
 use crate::{ArrayView, ArrayViewMut};
 use crate::split_at::SplitPreference;
@@ -201,6 +202,81 @@ par_iter_view_wrapper!(ArrayViewMut, [Sync + Send]);
 
 use crate::{FoldWhile, NdProducer, Zip};
 
+/// Parallel producer wrapper for a producer that is not an array view,
+/// split in the same way as a `Zip`.
+struct ParallelNdProducer<P: NdProducer>(Zip<(P,), P::Dim>);
+
+impl<P> UnindexedProducer for ParallelNdProducer<P>
+    where P: NdProducer + Send,
+          P::Item: Send,
+{
+    type Item = P::Item;
+
+    fn split(self) -> (Self, Option<Self>) {
+        if !self.0.can_split() {
+            return (self, None)
+        }
+        let (a, b) = self.0.split();
+        (ParallelNdProducer(a), Some(ParallelNdProducer(b)))
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+        where F: Folder<Self::Item>,
+    {
+        self.0.fold_while(folder, |mut folder, elt| {
+            folder = folder.consume(elt);
+            if folder.full() {
+                FoldWhile::Done(folder)
+            } else {
+                FoldWhile::Continue(folder)
+            }
+        }).into_inner()
+    }
+}
+
+macro_rules! par_iter_producer_wrapper {
+    // thread_bounds are either Sync or Send + Sync
+    ($producer_name:ident, [$($thread_bounds:tt)*]) => {
+    /// Requires crate feature `rayon`.
+    impl<'a, A, D> IntoParallelIterator for $producer_name<'a, A, D>
+        where D: Dimension,
+              A: $($thread_bounds)*,
+    {
+        type Item = <Self as NdProducer>::Item;
+        type Iter = Parallel<Self>;
+        fn into_par_iter(self) -> Self::Iter {
+            Parallel {
+                iter: self,
+            }
+        }
+    }
+
+    impl<'a, A, D> ParallelIterator for Parallel<$producer_name<'a, A, D>>
+        where D: Dimension,
+              A: $($thread_bounds)*,
+    {
+        type Item = <$producer_name<'a, A, D> as NdProducer>::Item;
+        fn drive_unindexed<C>(self, consumer: C) -> C::Result
+            where C: UnindexedConsumer<Self::Item>
+        {
+            bridge_unindexed(ParallelNdProducer(Zip::from(self.iter)), consumer)
+        }
+
+        fn opt_len(&self) -> Option<usize> {
+            None
+        }
+    }
+    }
+}
+
+par_iter_producer_wrapper!(Windows, [Sync]);
+par_iter_producer_wrapper!(ExactChunks, [Sync]);
+par_iter_producer_wrapper!(ExactChunksMut, [Send + Sync]);
+par_iter_producer_wrapper!(Lanes, [Sync]);
+par_iter_producer_wrapper!(LanesMut, [Send + Sync]);
+par_iter_producer_wrapper!(Chunks, [Sync]);
+par_iter_producer_wrapper!(ChunksMut, [Send + Sync]);
+
 macro_rules! zip_impl {
     ($([$($p:ident)*],)+) => {
         $(
@@ -282,6 +358,12 @@ zip_impl! {
     [P1 P2 P3 P4],
     [P1 P2 P3 P4 P5],
     [P1 P2 P3 P4 P5 P6],
+    [P1 P2 P3 P4 P5 P6 P7],
+    [P1 P2 P3 P4 P5 P6 P7 P8],
+    [P1 P2 P3 P4 P5 P6 P7 P8 P9],
+    [P1 P2 P3 P4 P5 P6 P7 P8 P9 P10],
+    [P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11],
+    [P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11 P12],
 }
 
 /// A parallel iterator (unindexed) that produces the splits of the array
@@ -75,18 +75,24 @@ where
     D: RemoveAxis,
 {
     if arrays.is_empty() {
-        return Err(from_kind(ErrorKind::Unsupported));
+        return Err(from_kind(ErrorKind::Unsupported).with_operation("concatenate"));
     }
     let mut res_dim = arrays[0].raw_dim();
     if axis.index() >= res_dim.ndim() {
-        return Err(from_kind(ErrorKind::OutOfBounds));
+        return Err(from_kind(ErrorKind::OutOfBounds)
+            .with_operation("concatenate")
+            .with_axis(axis));
     }
     let common_dim = res_dim.remove_axis(axis);
-    if arrays
+    if let Some(i) = arrays
         .iter()
-        .any(|a| a.raw_dim().remove_axis(axis) != common_dim)
+        .position(|a| a.raw_dim().remove_axis(axis) != common_dim)
     {
-        return Err(from_kind(ErrorKind::IncompatibleShape));
+        return Err(from_kind(ErrorKind::IncompatibleShape)
+            .with_operation("concatenate")
+            .with_shapes(arrays[0].shape(), arrays[i].shape())
+            .with_axis(axis)
+            .with_index(i));
     }
 
     let stacked_dim = arrays.iter().fold(0, |acc, a| acc + a.len_of(axis));
@@ -143,17 +149,22 @@ where
     D::Larger: RemoveAxis,
 {
     if arrays.is_empty() {
-        return Err(from_kind(ErrorKind::Unsupported));
+        return Err(from_kind(ErrorKind::Unsupported).with_operation("stack"));
     }
     let common_dim = arrays[0].raw_dim();
     // Avoid panic on `insert_axis` call, return an Err instead of it.
     if axis.index() > common_dim.ndim() {
-        return Err(from_kind(ErrorKind::OutOfBounds));
+        return Err(from_kind(ErrorKind::OutOfBounds)
+            .with_operation("stack")
+            .with_axis(axis));
     }
     let mut res_dim = common_dim.insert_axis(axis);
 
-    if arrays.iter().any(|a| a.raw_dim() != common_dim) {
-        return Err(from_kind(ErrorKind::IncompatibleShape));
+    if let Some(i) = arrays.iter().position(|a| a.raw_dim() != common_dim) {
+        return Err(from_kind(ErrorKind::IncompatibleShape)
+            .with_operation("stack")
+            .with_shapes(arrays[0].shape(), arrays[i].shape())
+            .with_index(i));
     }
 
     res_dim.set_axis(axis, arrays.len());
@@ -0,0 +1,138 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::dimension::IntoDimension;
+use crate::imp_prelude::*;
+use crate::indexes::{indices, Indices};
+use crate::Layout;
+use crate::NdProducer;
+
+/// A producer that computes each of its items from the item's index.
+///
+/// `FnProducer` is the way to use your own data sources with [`Zip`](crate::Zip) and
+/// `par_azip!`: the function is called with the index of each item
+/// in the pattern form of the dimension (e.g. `(i, j)` for 2D), in the order that `Zip`
+/// visits the items.
+///
+/// The function must be `Clone` so that the producer can be split, which also
+/// makes it possible to use in parallel with `par_for_each` and similar methods
+/// (this requires the function to be `Send`, too).
+///
+/// The dimension type must be `Copy`, like for [`indices`](crate::indices), so the
+/// fixed dimensionalities `Ix1`, `Ix2`, etc. are supported, but `IxDyn` is not.
+///
+/// ```
+/// use ndarray::{Array2, FnProducer, Zip};
+///
+/// // a lazy source; the upper triangle of a 3 × 3 matrix of ones
+/// let upper = FnProducer::new((3, 3), |(i, j)| if i <= j { 1 } else { 0 });
+///
+/// let mut a = Array2::zeros((3, 3));
+/// Zip::from(&mut a).and(upper).for_each(|a, u| *a = 2 * u);
+/// assert_eq!(a, ndarray::array![[2, 2, 2], [0, 2, 2], [0, 0, 2]]);
+///
+/// // user storage, here a hash map of nonzero elements
+/// let sparse: std::collections::HashMap<_, _> = vec![((0, 1), 5), ((2, 0), 7)].into_iter().collect();
+/// let dense = Zip::from(FnProducer::new((3, 2), |ix| sparse.get(&ix).copied().unwrap_or(0)))
+///     .map_collect(|x| x);
+/// assert_eq!(dense, ndarray::array![[0, 5], [0, 0], [7, 0]]);
+/// ```
+#[derive(Clone)]
+pub struct FnProducer<D, F>
+where
+    D: Dimension,
+{
+    indices: Indices<D>,
+    f: F,
+}
+
+impl<D, F, T> FnProducer<D, F>
+where
+    D: Dimension + Copy,
+    F: Fn(D::Pattern) -> T,
+{
+    /// Create a producer of the given shape that computes the item at index `i` as `f(i)`.
+    pub fn new<Sh>(shape: Sh, f: F) -> Self
+    where
+        Sh: IntoDimension<Dim = D>,
+    {
+        FnProducer {
+            indices: indices(shape),
+            f,
+        }
+    }
+}
+
+// The producer is `Indices` with `f` applied to each index
+impl<D, F, T> NdProducer for FnProducer<D, F>
+where
+    D: Dimension + Copy,
+    F: Fn(D::Pattern) -> T + Clone,
+{
+    type Item = T;
+    type Dim = D;
+    type Ptr = <Indices<D> as NdProducer>::Ptr;
+    type Stride = usize;
+
+    private_impl! {}
+
+    #[doc(hidden)]
+    fn raw_dim(&self) -> Self::Dim {
+        self.indices.raw_dim()
+    }
+
+    #[doc(hidden)]
+    fn equal_dim(&self, dim: &Self::Dim) -> bool {
+        self.indices.equal_dim(dim)
+    }
+
+    #[doc(hidden)]
+    fn as_ptr(&self) -> Self::Ptr {
+        self.indices.as_ptr()
+    }
+
+    #[doc(hidden)]
+    fn layout(&self) -> Layout {
+        self.indices.layout()
+    }
+
+    #[doc(hidden)]
+    unsafe fn as_ref(&self, ptr: Self::Ptr) -> Self::Item {
+        (self.f)(self.indices.as_ref(ptr))
+    }
+
+    #[doc(hidden)]
+    unsafe fn uget_ptr(&self, i: &Self::Dim) -> Self::Ptr {
+        self.indices.uget_ptr(i)
+    }
+
+    #[doc(hidden)]
+    fn stride_of(&self, axis: Axis) -> Self::Stride {
+        self.indices.stride_of(axis)
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride {
+        self.indices.contiguous_stride()
+    }
+
+    #[doc(hidden)]
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+        let (a, b) = self.indices.split_at(axis, index);
+        (
+            FnProducer {
+                indices: a,
+                f: self.f.clone(),
+            },
+            FnProducer {
+                indices: b,
+                f: self.f,
+            },
+        )
+    }
+}
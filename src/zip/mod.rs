@@ -8,6 +8,7 @@
 
 #[macro_use]
 mod zipmacro;
+mod fn_producer;
 mod ndproducer;
 
 #[cfg(feature = "rayon")]
@@ -20,9 +21,11 @@ use crate::Layout;
 use crate::partial::Partial;
 
 use crate::indexes::{indices, Indices};
+use crate::iter::{Lanes, LanesMut};
 use crate::split_at::{SplitPreference, SplitAt};
 use crate::dimension;
 
+pub use self::fn_producer::FnProducer;
 pub use self::ndproducer::{NdProducer, IntoNdProducer, Offset};
 
 /// Return if the expression is a break value.
@@ -129,7 +132,7 @@ trait ZippableTuple: Sized {
 /// better and the compiler can usually vectorize the loop (if applicable).
 ///
 /// The order elements are visited is not specified. The producers don’t have to
-/// have the same item type.
+/// have the same item type. A zip can have up to twelve producers.
 ///
 /// The `Zip` has two methods for function application: `for_each` and
 /// `fold_while`. The zip object can be split, which allows parallelization.
@@ -245,6 +248,113 @@ where
     }
 }
 
+impl<'a, A, D> Zip<(Lanes<'a, A, D>,), D>
+where
+    D: Dimension,
+{
+    /// Create a new `Zip` over the lanes along `axis` of the input array or view `p`.
+    ///
+    /// The items are one-dimensional array views of the lanes (see
+    /// [`.lanes()`](ArrayBase::lanes)), and the Zip takes the shape of `p` with
+    /// `axis` removed. Lanes of more arrays can be included with
+    /// [`.and_lanes()`](Zip::and_lanes) and [`.and_lanes_mut()`](Zip::and_lanes_mut).
+    ///
+    /// Use the axis with the smallest stride, for example from
+    /// [`.min_stride_axis()`](ArrayBase::min_stride_axis), for contiguous lanes;
+    /// [`Zip::lanes_contiguous`] picks that axis automatically.
+    ///
+    /// ***Panics*** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, Zip};
+    ///
+    /// let a = array![[1., 2., 3.], [4., 5., 6.]];
+    /// let mut b = Array2::zeros((2, 3));
+    ///
+    /// // normalize the rows of `a` into `b`
+    /// let axis = a.min_stride_axis();
+    /// Zip::lanes(axis, &a)
+    ///     .and_lanes_mut(axis, &mut b)
+    ///     .for_each(|a, mut b| {
+    ///         let sum = a.sum();
+    ///         b.zip_mut_with(&a, |b, &a| *b = a / sum);
+    ///     });
+    /// assert_eq!(b, array![[1. / 6., 2. / 6., 3. / 6.], [4. / 15., 5. / 15., 6. / 15.]]);
+    /// ```
+    pub fn lanes<P, Di>(axis: Axis, p: P) -> Self
+    where
+        P: IntoNdProducer<Dim = Di, Output = ArrayView<'a, A, Di>>,
+        Di: Dimension<Smaller = D>,
+    {
+        Zip::from(Lanes::new(p.into_producer(), axis))
+    }
+
+    /// Create a new `Zip` over the lanes of `p` along its axis with the smallest
+    /// stride (see [`.min_stride_axis()`](ArrayBase::min_stride_axis)), so that
+    /// the lanes are as close to contiguous in memory as possible.
+    ///
+    /// This is [`Zip::lanes`] with the axis picked from the layout of `p`. To
+    /// include lanes of more arrays, pick the axis from `p` and use [`Zip::lanes`],
+    /// so that the same axis can be passed to [`.and_lanes()`](Zip::and_lanes).
+    ///
+    /// ***Panics*** if `p` is zero-dimensional.
+    ///
+    /// ```
+    /// use ndarray::{array, Zip};
+    ///
+    /// let a = array![[1., 2., 3.], [4., 5., 6.]];
+    ///
+    /// // the lanes are the columns of the transposed array
+    /// let mut sums = Vec::new();
+    /// Zip::lanes_contiguous(a.t()).for_each(|lane| sums.push(lane.sum()));
+    /// assert_eq!(sums, vec![6., 15.]);
+    /// ```
+    pub fn lanes_contiguous<P, Di>(p: P) -> Self
+    where
+        P: IntoNdProducer<Dim = Di, Output = ArrayView<'a, A, Di>>,
+        Di: Dimension<Smaller = D>,
+    {
+        let p = p.into_producer();
+        let axis = p.min_stride_axis();
+        Zip::from(Lanes::new(p, axis))
+    }
+}
+
+impl<'a, A, D> Zip<(LanesMut<'a, A, D>,), D>
+where
+    D: Dimension,
+{
+    /// Create a new `Zip` over the lanes along `axis` of the mutable array or view `p`.
+    ///
+    /// The items are one-dimensional mutable array views of the lanes.
+    /// See [`Zip::lanes`] for more information.
+    ///
+    /// ***Panics*** if `axis` is out of bounds.
+    pub fn lanes_mut<P, Di>(axis: Axis, p: P) -> Self
+    where
+        P: IntoNdProducer<Dim = Di, Output = ArrayViewMut<'a, A, Di>>,
+        Di: Dimension<Smaller = D>,
+    {
+        Zip::from(LanesMut::new(p.into_producer(), axis))
+    }
+
+    /// Create a new `Zip` over the lanes of the mutable array or view `p` along
+    /// its axis with the smallest stride.
+    ///
+    /// See [`Zip::lanes_contiguous`] for more information.
+    ///
+    /// ***Panics*** if `p` is zero-dimensional.
+    pub fn lanes_mut_contiguous<P, Di>(p: P) -> Self
+    where
+        P: IntoNdProducer<Dim = Di, Output = ArrayViewMut<'a, A, Di>>,
+        Di: Dimension<Smaller = D>,
+    {
+        let p = p.into_producer();
+        let axis = p.min_stride_axis();
+        Zip::from(LanesMut::new(p, axis))
+    }
+}
+
 #[inline]
 fn zip_dimension_check<D, P>(dimension: &D, part: &P)
 where
@@ -506,6 +616,12 @@ offset_impl! {
     [A B C D][ a b c d],
     [A B C D E][ a b c d e],
     [A B C D E F][ a b c d e f],
+    [A B C D E F G][ a b c d e f g],
+    [A B C D E F G H][ a b c d e f g h],
+    [A B C D E F G H I][ a b c d e f g h i],
+    [A B C D E F G H I J][ a b c d e f g h i j],
+    [A B C D E F G H I J K][ a b c d e f g h i j k],
+    [A B C D E F G H I J K L][ a b c d e f g h i j k l],
 }
 
 macro_rules! zipt_impl {
@@ -565,6 +681,12 @@ zipt_impl! {
     [A B C D][ a b c d],
     [A B C D E][ a b c d e],
     [A B C D E F][ a b c d e f],
+    [A B C D E F G][ a b c d e f g],
+    [A B C D E F G H][ a b c d e f g h],
+    [A B C D E F G H I][ a b c d e f g h i],
+    [A B C D E F G H I J][ a b c d e f g h i j],
+    [A B C D E F G H I J K][ a b c d e f g h i j k],
+    [A B C D E F G H I J K L][ a b c d e f g h i j k l],
 }
 
 macro_rules! map_impl {
@@ -672,6 +794,59 @@ macro_rules! map_impl {
                 }).is_done()
             }
 
+            /// Tests if any element of the iterator matches a predicate.
+            ///
+            /// Returns `true` if `predicate` evaluates to `true` for any elements;
+            /// the iteration stops at the first such element.
+            /// Returns `false` if the input arrays are empty.
+            ///
+            /// Example:
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            /// let a = array![1, 2, 3];
+            /// let b = array![1, 4, 8];
+            /// assert!(Zip::from(&a).and(&b).any(|&a, &b| a * a != b));
+            /// ```
+            pub fn any<F>(mut self, mut predicate: F) -> bool
+                where F: FnMut($($p::Item),*) -> bool
+            {
+                self.for_each_core((), move |_, args| {
+                    let ($($p,)*) = args;
+                    if predicate($($p),*) {
+                        FoldWhile::Done(())
+                    } else {
+                        FoldWhile::Continue(())
+                    }
+                }).is_done()
+            }
+
+            /// Map each element tuple with `map` and combine the results with `reduce`,
+            /// starting from the value `identity()`.
+            ///
+            /// `identity()` should be the neutral element of `reduce`, so that the
+            /// method can be parallelized the same way
+            #[cfg_attr(feature = "rayon", doc = "with [`par_map_reduce`](Zip::par_map_reduce).")]
+            #[cfg_attr(not(feature = "rayon"), doc = "with `par_map_reduce` (crate feature `rayon`).")]
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            /// let a = array![[1., 2.], [3., 4.]];
+            /// let b = array![[4., 3.], [2., 1.]];
+            ///
+            /// // squared distance
+            /// let dist = Zip::from(&a).and(&b)
+            ///     .map_reduce(|&x, &y| (x - y) * (x - y), || 0., |acc, d| acc + d);
+            /// assert_eq!(dist, 20.);
+            /// ```
+            pub fn map_reduce<R, M, ID, RF>(self, mut map: M, identity: ID, mut reduce: RF) -> R
+                where M: FnMut($($p::Item),*) -> R,
+                      ID: FnOnce() -> R,
+                      RF: FnMut(R, R) -> R,
+            {
+                self.fold(identity(), move |acc, $($p),*| reduce(acc, map($($p),*)))
+            }
+
             expand_if!(@bool [$notlast]
 
             /// Include the producer `p` in the Zip.
@@ -719,6 +894,37 @@ macro_rules! map_impl {
                 self.build_and(part)
             }
 
+            /// Include the lanes along `axis` of the array or view `p` in the Zip.
+            ///
+            /// The items are one-dimensional array views of the lanes; see
+            /// [`Zip::lanes`] for more information. The lanes don't need to have the same
+            /// length as the lanes of other producers.
+            ///
+            /// ***Panics*** if `axis` is out of bounds, or if the shape of `p` with `axis`
+            /// removed doesn’t match the Zip’s exactly.
+            pub fn and_lanes<'a, P, A, Di>(self, axis: Axis, p: P)
+                -> Zip<($($p,)* Lanes<'a, A, D>, ), D>
+                where P: IntoNdProducer<Dim = Di, Output = ArrayView<'a, A, Di>>,
+                      Di: Dimension<Smaller = D>,
+            {
+                self.and(Lanes::new(p.into_producer(), axis))
+            }
+
+            /// Include the lanes along `axis` of the mutable array or view `p` in the Zip.
+            ///
+            /// The items are one-dimensional mutable array views of the lanes; see
+            /// [`Zip::lanes`] for more information.
+            ///
+            /// ***Panics*** if `axis` is out of bounds, or if the shape of `p` with `axis`
+            /// removed doesn’t match the Zip’s exactly.
+            pub fn and_lanes_mut<'a, P, A, Di>(self, axis: Axis, p: P)
+                -> Zip<($($p,)* LanesMut<'a, A, D>, ), D>
+                where P: IntoNdProducer<Dim = Di, Output = ArrayViewMut<'a, A, Di>>,
+                      Di: Dimension<Smaller = D>,
+            {
+                self.and(LanesMut::new(p.into_producer(), axis))
+            }
+
             fn build_and<P>(self, part: P) -> Zip<($($p,)* P, ), D>
                 where P: NdProducer<Dim=D>,
             {
@@ -918,7 +1124,13 @@ map_impl! {
     [true P1 P2 P3],
     [true P1 P2 P3 P4],
     [true P1 P2 P3 P4 P5],
-    [false P1 P2 P3 P4 P5 P6],
+    [true P1 P2 P3 P4 P5 P6],
+    [true P1 P2 P3 P4 P5 P6 P7],
+    [true P1 P2 P3 P4 P5 P6 P7 P8],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9 P10],
+    [true P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11],
+    [false P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11 P12],
 }
 
 /// Value controlling the execution of `.fold_while` on `Zip`.
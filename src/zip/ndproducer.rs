@@ -51,6 +51,9 @@ where
 /// (`AxisIter` traverses a one dimensional sequence, along an axis, while
 /// *producing* multidimensional items).
 ///
+/// This trait can not be implemented outside of ndarray; use
+/// [`FnProducer`](struct.FnProducer.html) to make a producer from your own data source.
+///
 /// See also [`IntoNdProducer`](trait.IntoNdProducer.html)
 pub trait NdProducer {
     /// The element produced per iteration.
@@ -118,6 +121,28 @@ impl<T> Offset for *mut T {
     private_impl! {}
 }
 
+// A linear index, for producers that compute their items from their position
+impl Offset for usize {
+    type Stride = usize;
+    unsafe fn stride_offset(self, s: Self::Stride, index: usize) -> Self {
+        self + s * index
+    }
+    private_impl! {}
+}
+
+// A pair of pointers, for producers that combine two other producers
+impl<P, Q> Offset for (P, Q)
+where
+    P: Offset,
+    Q: Offset,
+{
+    type Stride = (P::Stride, Q::Stride);
+    unsafe fn stride_offset(self, s: Self::Stride, index: usize) -> Self {
+        (self.0.stride_offset(s.0, index), self.1.stride_offset(s.1, index))
+    }
+    private_impl! {}
+}
+
 /// An array reference is an n-dimensional producer of element references
 /// (like ArrayView).
 impl<'a, A: 'a, S, D> IntoNdProducer for &'a ArrayBase<S, D>
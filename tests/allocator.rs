@@ -0,0 +1,107 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use ndarray::prelude::*;
+use ndarray::{AllocError, ArrayAllocator, Zip};
+
+/// Allocator that keeps track of the number of live allocations
+#[derive(Clone, Default)]
+struct CountingAlloc {
+    live: Arc<AtomicUsize>,
+}
+
+impl CountingAlloc {
+    fn live(&self) -> usize {
+        self.live.load(Ordering::SeqCst)
+    }
+}
+
+unsafe impl ArrayAllocator for CountingAlloc {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        self.live.fetch_add(1, Ordering::SeqCst);
+        NonNull::new(unsafe { System.alloc(layout) }).ok_or(AllocError)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.live.fetch_sub(1, Ordering::SeqCst);
+        System.dealloc(ptr.as_ptr(), layout)
+    }
+}
+
+#[test]
+fn allocator_in_constructors() {
+    let alloc = CountingAlloc::default();
+    {
+        let a = Array::from_elem_in((3, 4), 1, alloc.clone());
+        assert_eq!(alloc.live(), 1);
+        assert_eq!(a, Array2::<i32>::from_elem((3, 4), 1));
+
+        let b = a.clone();
+        assert_eq!(alloc.live(), 2);
+        assert_eq!(b.allocator().live(), 2);
+
+        let c = Array::from_shape_vec_in((2, 3), vec![1, 2, 3, 4, 5, 6], alloc.clone()).unwrap();
+        assert_eq!(c, array![[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(alloc.live(), 3);
+        assert!(Array::from_shape_vec_in((2, 2), vec![1, 2, 3], alloc.clone()).is_err());
+
+        let mut d = Array::uninit_in(5, alloc.clone());
+        d.fill(std::mem::MaybeUninit::new(2.));
+        let d = unsafe { d.assume_init() };
+        assert_eq!(d, Array::from_elem(5, 2.));
+        assert_eq!(alloc.live(), 4);
+
+        // converting to vec and owned arrays moves the elements to the global allocator
+        assert_eq!(c.into_raw_vec(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(alloc.live(), 3);
+        let e = b.into_owned();
+        assert_eq!(e, Array::from_elem((3, 4), 1));
+        assert_eq!(alloc.live(), 2);
+    }
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn allocator_methods() {
+    let alloc = CountingAlloc::default();
+    {
+        let mut a = Array::from_elem_in((0, 3), String::new(), alloc.clone());
+        for i in 0..10 {
+            let row = [i.to_string(), "a".to_string(), "b".to_string()];
+            a.push_row(aview1(&row)).unwrap();
+        }
+        a.push_column(Array1::from_elem(10, "c".to_string()).view()).unwrap();
+        assert_eq!(a.dim(), (10, 4));
+        assert_eq!(a[[7, 0]], "7");
+        assert_eq!(a[[9, 3]], "c");
+        assert_eq!(alloc.live(), 1);
+
+        let b = Array::from_shape_vec_in(5, vec![1., 2., 3., 4., 5.], alloc.clone()).unwrap();
+        let c = &b * 2.;
+        assert_eq!(c, array![2., 4., 6., 8., 10.]);
+        let mut sum = 0.;
+        Zip::from(&b).and(&c).for_each(|x, y| sum += x * y);
+        assert_eq!(sum, 110.);
+
+        let s: Vec<_> = a.slice_move(s![..2, ..2]).into_iter().collect();
+        assert_eq!(s, ["0", "a", "1", "a"]);
+    }
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn allocator_raw_parts() {
+    let alloc = CountingAlloc::default();
+    let a = Array::from_shape_vec_in((2, 2), vec![1u32, 2, 3, 4], alloc.clone()).unwrap();
+    let align = a.alignment();
+    let (ptr, len, capacity, alloc2) = a.into_raw_parts_with_alloc();
+    assert_eq!(len, 4);
+    unsafe {
+        assert_eq!(std::slice::from_raw_parts(ptr, len), &[1, 2, 3, 4]);
+        let layout = Layout::from_size_align(capacity * 4, align).unwrap();
+        alloc2.deallocate(NonNull::new(ptr as *mut u8).unwrap(), layout);
+    }
+    assert_eq!(alloc.live(), 0);
+}
@@ -24,6 +24,19 @@ fn push_row() {
                [4., 5., 6., 7., 2.]]);
 }
 
+#[test]
+fn push_row_error_context() {
+    let mut a = Array::zeros((2, 4));
+    let err = a.push_row(aview1(&[1., 2., 3.])).unwrap_err();
+    assert_eq!(err.operation(), Some("push_row"));
+    assert_eq!(err.expected_shape(), Some(&[2, 4][..]));
+    assert_eq!(err.actual_shape(), Some(&[1, 3][..]));
+    assert_eq!(err.axis(), Some(Axis(0)));
+    assert_eq!(err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes (operation: push_row, \
+         expected shape: [2, 4], actual shape: [1, 3], axis: 0)");
+}
+
 #[test]
 fn push_row_wrong_layout() {
     let mut a = Array::zeros((0, 4));
@@ -401,3 +414,25 @@ fn zero_dimensional_ok() {
     a.push(Axis(0), one).unwrap();
     assert_eq!(a, array![2, 1, 1]);
 }
+
+#[test]
+fn append_keeps_alignment() {
+    let mut a = Array::<f64, _>::zeros_aligned((0, 4), 64);
+    for i in 0..20 {
+        a.push_row(aview1(&[i as f64; 4])).unwrap();
+        assert_eq!(a.alignment(), 64);
+        assert_eq!(a.as_ptr() as usize % 64, 0);
+    }
+    // change of memory layout
+    a.push_column(Array::zeros(20).view()).unwrap();
+    assert_eq!(a.alignment(), 64);
+    assert_eq!(a.as_ptr() as usize % 64, 0);
+    assert_eq!(a.shape(), &[20, 5]);
+    assert_eq!(a.row(19), aview1(&[19., 19., 19., 19., 0.]));
+
+    let mut s = Array::from_elem_aligned((1, 2), String::from("a"), 32);
+    s.push_row(aview1(&[String::from("b"), String::from("c")])).unwrap();
+    s.push_column(aview1(&[String::from("d"), String::from("e")])).unwrap();
+    assert_eq!(s.alignment(), 32);
+    assert_eq!(s, array![["a", "a", "d"], ["b", "c", "e"]].map(|s| s.to_string()));
+}
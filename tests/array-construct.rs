@@ -286,3 +286,70 @@ fn maybe_uninit_1() {
 
     }
 }
+
+#[test]
+fn aligned_constructors() {
+    fn is_aligned<T>(ptr: *const T, align: usize) -> bool {
+        ptr as usize % align == 0
+    }
+
+    for &align in &[1, 8, 16, 32, 64, 4096] {
+        let a = Array::<f32, _>::zeros_aligned((7, 3), align);
+        assert_eq!(a, Array::zeros((7, 3)));
+        assert_eq!(a.alignment(), Ord::max(align, 4));
+        assert!(is_aligned(a.as_ptr(), align));
+
+        let b = ArcArray::from_elem_aligned((2, 3).f(), 1u8, align);
+        assert_eq!(b.alignment(), align);
+        assert!(is_aligned(b.as_ptr(), align));
+        assert_eq!(b.strides(), &[1, 2]);
+    }
+
+    let mut c = Array::<f64, _>::uninit_aligned(10, 32);
+    c.fill(std::mem::MaybeUninit::new(2.));
+    let c = unsafe { c.assume_init() };
+    assert_eq!(c.alignment(), 32);
+    assert_eq!(c, Array::from_elem(10, 2.));
+    assert_eq!(c.into_raw_vec(), vec![2.; 10]);
+
+    // elements with destructors and zero-sized elements
+    let d = Array::from_elem_aligned((4, 4), String::from("x"), 64);
+    assert!(d.iter().all(|s| s == "x"));
+    let e = Array::from_elem_aligned(5, (), 64);
+    assert_eq!(e.len(), 5);
+    assert_eq!(e.clone().into_raw_vec(), vec![(); 5]);
+}
+
+#[test]
+fn aligned_storage_is_preserved() {
+    let a = Array::from_elem_aligned((10, 10), 0u16, 64);
+    assert_eq!(a.clone().alignment(), 64);
+    assert_eq!(a.to_owned().alignment(), 64);
+    assert_eq!(a.to_shared().alignment(), 64);
+
+    let mut b = Array::zeros((2, 2));
+    b.clone_from(&a);
+    assert_eq!(b.alignment(), 64);
+    assert_eq!(b, a);
+
+    // copy on write keeps the alignment
+    let shared = a.into_shared();
+    let mut copy = shared.clone();
+    copy[[1, 1]] = 1;
+    assert_eq!(copy.alignment(), 64);
+    assert_eq!(copy.as_ptr() as usize % 64, 0);
+    assert_eq!(shared[[1, 1]], 0);
+
+    // non-contiguous source
+    let c = array![[1, 2, 3], [4, 5, 6]];
+    let d = c.slice(s![.., ..;2]).to_owned_aligned(128);
+    assert_eq!(d, array![[1, 3], [4, 6]]);
+    assert_eq!(d.alignment(), 128);
+    assert_eq!(d.as_ptr() as usize % 128, 0);
+}
+
+#[test]
+#[should_panic(expected = "power of two")]
+fn aligned_not_power_of_two() {
+    let _ = Array::<f32, _>::zeros_aligned(10, 24);
+}
@@ -652,6 +652,39 @@ fn test_cow_shrink() {
     assert_eq!(before[1], 5);
 }
 
+#[test]
+fn test_cow_slice_shared() {
+    let mat = ArcArray::from_shape_fn((4, 6), |(i, j)| i * 6 + j);
+    let mut tiles = vec![];
+    for i in 0..2 {
+        for j in 0..3 {
+            tiles.push(mat.slice_shared(s![2 * i..2 * i + 2, 2 * j..2 * j + 2]));
+        }
+    }
+    // tiles share the storage of `mat`
+    assert_eq!(tiles[0].as_ptr(), mat.as_ptr());
+    assert_eq!(tiles[4].as_ptr(), &mat[[2, 2]] as *const _);
+
+    let handles = tiles
+        .into_iter()
+        .map(|mut tile| {
+            std::thread::spawn(move || {
+                tile.map_inplace(|x| *x *= 10);
+                tile
+            })
+        })
+        .collect::<Vec<_>>();
+    for (k, handle) in handles.into_iter().enumerate() {
+        let tile = handle.join().unwrap();
+        let (i, j) = (k / 3, k % 3);
+        let orig = mat.slice(s![2 * i..2 * i + 2, 2 * j..2 * j + 2]);
+        assert_eq!(tile, &orig * 10);
+        // only the visible elements were copied
+        assert_eq!(tile.into_owned().into_raw_vec().len(), 4);
+    }
+    assert_eq!(mat, ArcArray::from_shape_fn((4, 6), |(i, j)| i * 6 + j));
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn test_sub() {
@@ -7,7 +7,7 @@
 )]
 
 use ndarray::prelude::*;
-use ndarray::Zip;
+use ndarray::{FnProducer, Zip};
 
 use itertools::{assert_equal, cloned};
 
@@ -437,6 +437,48 @@ fn test_indices_split_1() {
     }
 }
 
+#[test]
+fn test_zip_many_producers() {
+    let a = Array::from_shape_fn((5, 6), |(i, j)| (i * 6 + j) as f32);
+    let inputs: Vec<_> = (0..10).map(|k| &a + k as f32).collect();
+    let mut sum = Array::zeros(a.raw_dim());
+
+    azip!((s in &mut sum, &b in &inputs[0], &c in &inputs[1], &d in &inputs[2],
+           &e in &inputs[3], &f in &inputs[4], &g in &inputs[5], &h in &inputs[6],
+           &i in &inputs[7], &j in &inputs[8], &k in &inputs[9])
+          *s = b + c + d + e + f + g + h + i + j + k);
+    assert_eq!(sum, &a * 10. + 45.);
+
+    let total = Zip::from(&inputs[0]).and(&inputs[1]).and(&inputs[2]).and(&inputs[3])
+        .and(&inputs[4]).and(&inputs[5]).and(&inputs[6]).and(&inputs[7])
+        .and(&inputs[8]).and(&inputs[9]).and(&sum)
+        .map_collect(|&b, &c, &d, &e, &f, &g, &h, &i, &j, &k, &s| {
+            b + c + d + e + f + g + h + i + j + k - s
+        });
+    assert_eq!(total, Array::zeros(a.raw_dim()));
+}
+
+#[test]
+fn test_fn_producer() {
+    let a = Array::from_shape_fn((5, 7).f(), |(i, j)| i * 10 + j);
+    let p = FnProducer::new((5, 7), |(i, j)| i * 10 + j);
+    let mut count = 0;
+    Zip::from(&a).and(p).for_each(|&x, y| {
+        assert_eq!(x, y);
+        count += 1;
+    });
+    assert_eq!(count, a.len());
+
+    // split halves compute the items of their own part
+    let p = FnProducer::new((5, 7), |(i, j)| (i, j));
+    let (left, right) = Zip::indexed(&a).and(p).split();
+    left.for_each(|i, _, j| assert_eq!(i, j));
+    right.for_each(|i, _, j| assert_eq!(i, j));
+
+    let b = Zip::from(FnProducer::new(4, |i| i as f64 / 2.)).map_collect(|x| x * 2.);
+    assert_eq!(b, array![0., 1., 2., 3.]);
+}
+
 #[test]
 fn test_zip_all() {
     let a = Array::<f32, _>::zeros(62);
@@ -455,3 +497,84 @@ fn test_zip_all_empty_array() {
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| true));
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| false));
 }
+
+#[test]
+fn test_zip_any() {
+    let a = Array::<f32, _>::zeros(62);
+    let mut b = Array::<f32, _>::ones(62);
+    assert_eq!(false, Zip::from(&a).and(&b).any(|&x, &y| x == y));
+    b[61] = 0.0;
+    assert_eq!(true, Zip::from(&a).and(&b).any(|&x, &y| x == y));
+    let e = Array::<f32, _>::zeros(0);
+    assert_eq!(false, Zip::from(&e).any(|_| true));
+}
+
+#[test]
+fn test_zip_map_reduce() {
+    let a = Array::from_shape_fn((7, 9).f(), |(i, j)| (i * 9 + j) as i64);
+    let b = a.t().to_owned();
+    let dot = Zip::from(&a).and(&b.t())
+        .map_reduce(|&x, &y| x * y, || 0, |acc, p| acc + p);
+    assert_eq!(dot, a.iter().map(|x| x * x).sum());
+
+    let max = Zip::from(&a).map_reduce(|&x| x, || i64::MIN, i64::max);
+    assert_eq!(max, 62);
+}
+
+#[test]
+fn test_zip_lanes() {
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| (i * 30 + j * 6 + k) as f64);
+    let b = a.view().permuted_axes([2, 0, 1]).to_owned();
+    let mut c = Array::zeros((4, 5, 3));
+
+    for &axis in &[Axis(0), Axis(1), Axis(2)] {
+        let mut count = 0;
+        Zip::lanes(axis, &a).and_lanes(axis, &a.view()).for_each(|x, y| {
+            assert_eq!(x, y);
+            assert_eq!(x.len(), a.len_of(axis));
+            count += 1;
+        });
+        assert_eq!(count, a.len() / a.len_of(axis));
+    }
+
+    // lanes of different lengths and axes in different arrays
+    Zip::lanes(Axis(2), &a)
+        .and_lanes(Axis(0), &b)
+        .and_lanes_mut(Axis(2), &mut c)
+        .for_each(|x, y, mut z| {
+            assert_eq!(x, y);
+            z.fill(x.sum());
+        });
+    assert_eq!(c.index_axis(Axis(2), 0), a.sum_axis(Axis(2)));
+    assert_eq!(c.index_axis(Axis(2), 2), a.sum_axis(Axis(2)));
+    assert_eq!(a.min_stride_axis(), Axis(2));
+    assert_eq!(b.min_stride_axis(), Axis(0));
+}
+
+#[test]
+fn test_zip_lanes_contiguous() {
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| (i * 30 + j * 6 + k) as f64);
+    let b = a.view().permuted_axes([2, 0, 1]);
+    let c = a.slice(s![..;-1, .., ..;2]);
+
+    for (v, axis) in vec![(a.view(), Axis(2)), (b, Axis(0)), (c, Axis(2))] {
+        let mut lanes = Vec::new();
+        Zip::lanes_contiguous(&v).for_each(|lane| lanes.push(lane));
+        assert_eq!(lanes, v.lanes(axis).into_iter().collect::<Vec<_>>());
+    }
+
+    let mut d = Array::<f64, _>::zeros((4, 5).f());
+    Zip::lanes_mut_contiguous(&mut d).for_each(|mut lane| {
+        assert_eq!(lane.len(), 4);
+        assert_eq!(lane.strides(), &[1]);
+        lane.fill(1.);
+    });
+    assert_eq!(d, Array::ones((4, 5)));
+}
+
+#[test]
+#[should_panic]
+fn test_zip_lanes_shape_mismatch() {
+    let a = Array::<f64, _>::zeros((4, 5));
+    Zip::lanes(Axis(0), &a).and_lanes(Axis(1), &a);
+}
@@ -7,6 +7,7 @@
 )]
 
 use ndarray::prelude::*;
+use ndarray::Zip;
 
 #[test]
 #[cfg(feature = "std")]
@@ -99,3 +100,118 @@ fn chunks_different_size_3() {
     let mut a = Array::<f32, _>::zeros(vec![2, 3]);
     a.exact_chunks_mut(vec![2, 3, 4]);
 }
+
+#[test]
+fn chunks_with_remainder() {
+    use ndarray::NdProducer;
+    let a = Array::from_iter(0..63).into_shape((7, 9)).unwrap();
+
+    let (m, n) = a.dim();
+    for i in 1..=m + 1 {
+        for j in 1..=n + 1 {
+            let (ci, cj) = ((m + i - 1) / i, (n + j - 1) / j);
+            let c = a.chunks((i, j));
+            assert_eq!(c.raw_dim(), Dim([ci, cj]));
+            assert_eq!(c.into_iter().len(), ci * cj);
+            // the chunks cover the array
+            assert_eq!(a.chunks((i, j)).into_iter().map(|c| c.sum()).sum::<i32>(), a.sum());
+
+            Zip::indexed(a.chunks((i, j))).for_each(|(x, y), elt| {
+                let (x, y) = ((x * i) as isize, (y * j) as isize);
+                assert_eq!(elt, a.slice(s![x..(x + i as isize).min(m as isize),
+                                           y..(y + j as isize).min(n as isize)]));
+            });
+
+            let (c1, c2) = a.chunks((i, j)).split_at(Axis(1), cj / 2);
+            assert_eq!(c1.into_iter().count(), ci * (cj / 2));
+            itertools::assert_equal(
+                c2,
+                a.slice(s![.., (cj / 2 * j) as isize..]).chunks((i, j)),
+            );
+        }
+    }
+    assert_eq!(a.slice(s![..0, ..]).chunks((2, 2)).into_iter().count(), 0);
+}
+
+#[test]
+fn chunks_mut_with_remainder() {
+    let mut a = Array::zeros((5, 8));
+    Zip::indexed(a.chunks_mut((2, 3))).for_each(|(i, j), mut chunk| {
+        chunk.fill(i * 3 + j);
+    });
+    let ans = array![
+        [0, 0, 0, 1, 1, 1, 2, 2],
+        [0, 0, 0, 1, 1, 1, 2, 2],
+        [3, 3, 3, 4, 4, 4, 5, 5],
+        [3, 3, 3, 4, 4, 4, 5, 5],
+        [6, 6, 6, 7, 7, 7, 8, 8]
+    ];
+    assert_eq!(a, ans);
+
+    // negative strides
+    let mut b = Array::zeros((5, 8));
+    let mut b_rev = b.slice_mut(s![..;-1, ..]);
+    for (i, mut chunk) in b_rev.chunks_mut((2, 3)).into_iter().enumerate() {
+        chunk.fill(i);
+    }
+    assert_eq!(b.slice(s![..;-1, ..]), ans);
+}
+
+#[test]
+fn chunks_dyn() {
+    use ndarray::NdProducer;
+    let a = ArrayD::from_shape_fn(IxDyn(&[5, 4, 3]), |ix| ix[0] * 12 + ix[1] * 3 + ix[2]);
+    let chunks = a.chunks(IxDyn(&[2, 3, 3]));
+    assert_eq!(chunks.raw_dim(), IxDyn(&[3, 2, 1]));
+    itertools::assert_equal(
+        chunks.into_iter().map(|c| c.shape().to_vec()),
+        vec![
+            vec![2, 3, 3], vec![2, 1, 3],
+            vec![2, 3, 3], vec![2, 1, 3],
+            vec![1, 3, 3], vec![1, 1, 3],
+        ],
+    );
+    let first = ArrayD::from_shape_fn(IxDyn(&[3, 2, 1]), |ix| a[IxDyn(&[ix[0] * 2, ix[1] * 3, 0])]);
+    Zip::from(a.chunks(IxDyn(&[2, 3, 3]))).and(&first).for_each(|chunk, &first| {
+        assert_eq!(chunk[IxDyn(&[0, 0, 0])], first);
+    });
+
+    let mut b = ArrayD::zeros(IxDyn(&[5, 4]));
+    for (i, mut chunk) in b.chunks_mut(IxDyn(&[2, 3])).into_iter().enumerate() {
+        chunk.fill(i);
+    }
+    let ans = array![
+        [0, 0, 0, 1],
+        [0, 0, 0, 1],
+        [2, 2, 2, 3],
+        [2, 2, 2, 3],
+        [4, 4, 4, 5]
+    ];
+    assert_eq!(b, ans.into_dyn());
+}
+
+#[test]
+fn chunks_1d_zip() {
+    let a = Array::from_iter(0..10);
+    let sums = Zip::from(a.chunks(3)).map_collect(|c| c.sum());
+    assert_eq!(sums, array![3, 12, 21, 9]);
+    Zip::from(a.chunks(3)).and(&array![0, 3, 6, 9]).for_each(|c, &first| {
+        assert_eq!(c[0], first);
+    });
+
+    let mut b = Array::zeros(10);
+    Zip::indexed(b.chunks_mut(3)).for_each(|i, mut chunk| chunk.fill(i));
+    assert_eq!(b, array![0, 0, 0, 1, 1, 1, 2, 2, 2, 3]);
+    let mut b_rev = b.slice_mut(s![..;-1]);
+    Zip::from(b_rev.chunks_mut(4)).and(&array![7, 8, 9]).for_each(|mut chunk, &x| {
+        chunk.fill(x);
+    });
+    assert_eq!(b, array![9, 9, 8, 8, 8, 8, 7, 7, 7, 7]);
+}
+
+#[should_panic]
+#[test]
+fn chunks_zero_size() {
+    let a = Array::<f32, _>::zeros((2, 3));
+    a.chunks((2, 0));
+}
@@ -0,0 +1,83 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+use ndarray::Zip;
+
+#[test]
+fn lazy_arithmetic() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64);
+    let b = Array::linspace(1., 2., 4);
+    let c = a.t().to_owned();
+    let ct = c.t();
+
+    let expr = a.lazy() + b.lazy() * ct.lazy() / 2. - 1.;
+    assert_eq!(expr.raw_dim(), a.raw_dim());
+    assert_eq!(expr.eval(), &a + &(&b * &c.t() / 2.) - 1.);
+
+    assert_eq!((-a.lazy()).eval(), -&a);
+    assert_eq!((1. - a.lazy() % 3.).eval(), 1. - &a % 3.);
+    assert_eq!(a.lazy().map(f64::sqrt).eval(), a.mapv(f64::sqrt));
+    assert_eq!(
+        a.lazy().zip_with(b.lazy(), f64::max).eval(),
+        Zip::from(&a).and_broadcast(&b).map_collect(|&x, &y| x.max(y))
+    );
+
+    let i = Array::from_iter(0..6u32).into_shape((2, 3)).unwrap();
+    assert_eq!((!(i.lazy() << 2u32) & 0xf).eval(), (!(&i << 2u32)) & 0xf);
+}
+
+#[test]
+fn lazy_broadcast() {
+    let a = array![1, 2, 3];
+    let b = array![[10], [20]];
+    let c = array![[[100]], [[200]]];
+
+    let expr = a.lazy() + b.lazy() + c.lazy();
+    assert_eq!(expr.raw_dim(), Dim([2, 2, 3]));
+    assert_eq!(expr.eval(), &(&a + &b) + &c);
+
+    // the scalar is broadcast to the shape of the other operand
+    assert_eq!((5i32 * a.lazy()).eval(), array![5, 10, 15]);
+    let z = Array0::from_elem((), 7);
+    assert_eq!((z.lazy() - 1).eval(), arr0(6));
+}
+
+#[test]
+fn lazy_assign_to() {
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let b = array![1., 10., 100.];
+
+    let mut out = Array::zeros((2, 3));
+    (a.lazy() * b.lazy()).assign_to(&mut out);
+    assert_eq!(out, &a * &b);
+
+    // into a view, with negative strides, broadcasting the expression
+    let mut out = Array::zeros((4, 3));
+    let mut view = out.slice_mut(s![..;-2, ..]);
+    (b.lazy() + 1.).assign_to(&mut view);
+    assert_eq!(out.row(3), array![2., 11., 101.]);
+    assert_eq!(out.row(1), array![2., 11., 101.]);
+    assert_eq!(out.row(0), array![0., 0., 0.]);
+
+    // large enough for several passes over the inner loop
+    let x = Array::from_shape_fn((64, 65), |(i, j)| (i + j) as f32);
+    let mut y = Array::zeros((64, 65).f());
+    (x.lazy() * x.lazy() + x.lazy().map(|_| 1.)).assign_to(&mut y);
+    assert_eq!(y, &x * &x + 1.);
+}
+
+#[test]
+#[should_panic]
+fn lazy_incompatible_shapes() {
+    let a = Array::<f32, _>::zeros((2, 3));
+    let b = Array::<f32, _>::zeros((3, 2));
+    let _ = a.lazy() + b.lazy();
+}
+
+#[test]
+#[should_panic]
+fn lazy_assign_incompatible_shape() {
+    let a = Array::<f32, _>::zeros((2, 3));
+    let mut out = Array::<f32, _>::zeros((3, 3));
+    a.lazy().assign_to(&mut out);
+}
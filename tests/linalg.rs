@@ -0,0 +1,542 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "std")]
+
+use ndarray::linalg::{
+    cho_solve, cholesky, cond, det, eigh, eigvalsh, expm, inv, lstsq, matrix_power, matrix_rank,
+    nuclear_norm, pinv, qr, slogdet, solve, solve_triangular, spectral_norm, svd, Cholesky, LinalgError, LinalgFloat,
+    Lu, Qr, QrMode, SvdMode, Triangle,
+};
+use ndarray::prelude::*;
+use ndarray::{concatenate, stack, ErrorKind, Zip};
+use num_complex::Complex64;
+use num_traits::NumCast;
+
+/// A deterministic matrix with entries in [-1, 1)
+fn pseudo_random<A: LinalgFloat>(m: usize, n: usize, seed: u64) -> Array2<A> {
+    let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    Array::from_shape_simple_fn((m, n), || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let x = (state >> 11) as f64 / (1u64 << 53) as f64;
+        A::from_real(NumCast::from(2. * x - 1.).unwrap())
+    })
+}
+
+fn complex_pseudo_random(m: usize, n: usize, seed: u64) -> Array2<Complex64> {
+    let re = pseudo_random::<f64>(m, n, seed);
+    let im = pseudo_random::<f64>(m, n, seed + 1);
+    Zip::from(&re).and(&im).map_collect(|&re, &im| Complex64::new(re, im))
+}
+
+fn assert_close<A: LinalgFloat, D: Dimension>(a: &Array<A, D>, b: &Array<A, D>, tol: f64)
+where
+    A::Real: Into<f64>,
+{
+    assert_eq!(a.shape(), b.shape());
+    let max_diff = Zip::from(a)
+        .and(b)
+        .fold(0., |acc: f64, &x, &y| acc.max((x - y).abs().into()));
+    assert!(max_diff <= tol, "difference {:e}: {:?} != {:?}", max_diff, a, b);
+}
+
+#[test]
+fn lu_factors() {
+    for &n in &[0, 1, 5, 33, 70] {
+        let a = pseudo_random::<f64>(n, n, n as u64);
+        let lu = Lu::new(&a).unwrap();
+        let p = lu.permutation();
+        let pa = a.select(Axis(0), &p);
+        assert_close(&lu.l().dot(&lu.u()), &pa, 1e-12);
+        assert!(!lu.is_singular());
+
+        let a = complex_pseudo_random(n, n, n as u64);
+        let lu = Lu::new(&a.t()).unwrap();
+        let pa = a.t().select(Axis(0), &lu.permutation());
+        assert_close(&lu.l().dot(&lu.u()), &pa, 1e-12);
+    }
+}
+
+#[test]
+fn lu_solve_inv() {
+    for &n in &[1, 5, 33, 70] {
+        let a = pseudo_random::<f64>(n, n, 7);
+        let x = pseudo_random::<f64>(n, 3, 8);
+        let b = a.dot(&x);
+        assert_close(&solve(&a, &b).unwrap(), &x, 1e-9);
+        let b1 = b.column(1).to_owned();
+        assert_close(&solve(&a, &b1).unwrap(), &x.column(1).to_owned(), 1e-9);
+        assert_close(&inv(&a).unwrap().dot(&a), &Array2::eye(n), 1e-9);
+
+        let a = complex_pseudo_random(n, n, 9);
+        let x = complex_pseudo_random(n, 2, 10);
+        let b = a.dot(&x);
+        assert_close(&solve(&a, &b.t().t()).unwrap(), &x, 1e-9);
+        assert_close(&a.dot(&inv(&a).unwrap()), &Array2::eye(n), 1e-9);
+
+        let a = pseudo_random::<f32>(n, n, 11);
+        assert_close(&inv(&a).unwrap().dot(&a), &Array2::eye(n), 1e-2);
+    }
+}
+
+#[test]
+fn lu_det() {
+    let a = arr2(&[[0., 2., 1.], [3., 1., 0.], [2., 0., 0.]]);
+    assert!((det(&a).unwrap() - -2.).abs() < 1e-15);
+    let (sign, logabsdet): (f64, f64) = slogdet(&a).unwrap();
+    assert_eq!(sign, -1.);
+    assert!((logabsdet - 2f64.ln()).abs() < 1e-15);
+    assert_eq!(det(&Array2::<f64>::zeros((0, 0))).unwrap(), 1.);
+
+    // the determinant is a product over the diagonal of a triangular matrix
+    let mut a = pseudo_random::<f64>(50, 50, 3);
+    for i in 0..50 {
+        a[[i, i]] = 2. + i as f64 / 50.;
+        a.slice_mut(s![i, i + 1..]).fill(0.);
+    }
+    let d: f64 = a.diag().product();
+    assert!((det(&a).unwrap() - d).abs() <= 1e-12 * d);
+    let (sign, logabsdet) = slogdet(&a.t()).unwrap();
+    assert_eq!(sign, 1.);
+    assert!((logabsdet - d.ln()).abs() <= 1e-12);
+
+    let z = Complex64::new(0., 2.);
+    let a = arr2(&[[z, Complex64::new(1., 0.)], [Complex64::new(0., 0.), z]]);
+    assert_eq!(det(&a).unwrap(), Complex64::new(-4., 0.));
+    let (sign, logabsdet) = slogdet(&a).unwrap();
+    assert!((sign - Complex64::new(-1., 0.)).abs() < 1e-15);
+    assert!((logabsdet - 4f64.ln()).abs() < 1e-15);
+}
+
+#[test]
+fn lu_singular() {
+    let a = arr2(&[[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]]);
+    let lu = Lu::new(&a).unwrap();
+    assert!(lu.is_singular());
+    assert_eq!(lu.det(), 0.);
+    assert_eq!(lu.slogdet(), (0., f64::NEG_INFINITY));
+    assert_eq!(lu.inv(), Err(LinalgError::Singular));
+    assert_eq!(solve(&a, &arr1(&[1., 2., 3.])), Err(LinalgError::Singular));
+}
+
+#[test]
+fn lu_errors() {
+    let a = Array2::<f64>::eye(3);
+    assert_eq!(
+        inv(&a.slice(s![.., ..2])),
+        Err(LinalgError::NotSquare { rows: 3, cols: 2 })
+    );
+    match solve(&a, &Array::zeros((2, 4))) {
+        Err(LinalgError::Shape(err)) => {
+            assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+            assert_eq!(err.operation(), Some("solve"));
+            assert_eq!(err.expected_shape(), Some(&[3, 4][..]));
+            assert_eq!(err.actual_shape(), Some(&[2, 4][..]));
+        }
+        res => panic!("unexpected result {:?}", res),
+    }
+    assert!(solve(&a, &Array::zeros((3, 1, 1))).is_err());
+    assert!(solve(&a, &arr0(1.)).is_err());
+}
+
+fn conj_t(a: &Array2<Complex64>) -> Array2<Complex64> {
+    a.t().mapv(|x| x.conj())
+}
+
+#[test]
+fn cholesky_factors() {
+    for &n in &[0, 1, 5, 33, 70] {
+        let m = pseudo_random::<f64>(n, n, 5);
+        let a = m.dot(&m.t()) + Array2::<f64>::eye(n);
+        // the other triangle is not read
+        let a_lower = Array::from_shape_fn((n, n), |(i, j)| {
+            if i >= j {
+                a[[i, j]]
+            } else {
+                f64::NAN
+            }
+        });
+        let l = cholesky(&a_lower, Triangle::Lower).unwrap();
+        assert_close(&l.dot(&l.t()), &a, 1e-10);
+        assert_eq!(l, triangle(&l, Triangle::Lower));
+        let u = cholesky(&a_lower.t(), Triangle::Upper).unwrap();
+        assert_close(&u, &l.t().to_owned(), 1e-12);
+
+        let m = complex_pseudo_random(n, n, 6);
+        let a = m.dot(&conj_t(&m)) + Array2::<Complex64>::eye(n);
+        let l = cholesky(&a, Triangle::Lower).unwrap();
+        assert_close(&l.dot(&conj_t(&l)), &a, 1e-10);
+        let u = cholesky(&a, Triangle::Upper).unwrap();
+        assert_close(&conj_t(&u).dot(&u), &a, 1e-10);
+        assert!(l.diag().iter().all(|x| x.im == 0. && x.re > 0.));
+    }
+}
+
+fn triangle<A: LinalgFloat>(a: &Array2<A>, uplo: Triangle) -> Array2<A> {
+    Array::from_shape_fn(a.dim(), |(i, j)| match uplo {
+        Triangle::Upper if i <= j => a[[i, j]],
+        Triangle::Lower if i >= j => a[[i, j]],
+        _ => A::zero(),
+    })
+}
+
+#[test]
+fn cholesky_solve() {
+    for &n in &[1, 5, 40] {
+        let m = pseudo_random::<f64>(n, n, 1);
+        let a = m.dot(&m.t()) + Array2::<f64>::eye(n);
+        let x = pseudo_random::<f64>(n, 4, 2);
+        let b = a.dot(&x);
+        for &uplo in &[Triangle::Lower, Triangle::Upper] {
+            let chol = Cholesky::new(&a, uplo).unwrap();
+            assert_close(&chol.solve(&b).unwrap(), &x, 1e-9);
+            assert_close(&chol.solve(&b.column(0)).unwrap(), &x.column(0).to_owned(), 1e-9);
+            let factor = cholesky(&a, uplo).unwrap();
+            assert_close(&cho_solve(&factor, uplo, &b).unwrap(), &x, 1e-9);
+            assert!((chol.det() - det(&a).unwrap()).abs() <= 1e-9 * chol.det());
+        }
+
+        let m = complex_pseudo_random(n, n, 3);
+        let a = m.dot(&conj_t(&m)) + Array2::<Complex64>::eye(n);
+        let x = complex_pseudo_random(n, 3, 4);
+        let b = a.dot(&x);
+        for &uplo in &[Triangle::Lower, Triangle::Upper] {
+            let chol = Cholesky::new(&a, uplo).unwrap();
+            assert_close(&chol.solve(&b).unwrap(), &x, 1e-9);
+        }
+    }
+}
+
+#[test]
+fn cholesky_errors() {
+    let a = arr2(&[[1., 2.], [2., 1.]]);
+    assert_eq!(cholesky(&a, Triangle::Lower), Err(LinalgError::NotPositiveDefinite));
+    assert_eq!(
+        Cholesky::new(&a.slice(s![..1, ..]), Triangle::Lower).unwrap_err(),
+        LinalgError::NotSquare { rows: 1, cols: 2 }
+    );
+    let nan = arr2(&[[f64::NAN]]);
+    assert_eq!(cholesky(&nan, Triangle::Upper), Err(LinalgError::NotPositiveDefinite));
+    let err = cho_solve(&Array2::<f64>::eye(2), Triangle::Lower, &Array::zeros(3)).unwrap_err();
+    assert!(matches!(err, LinalgError::Shape(_)));
+}
+
+#[test]
+fn triangular_solve_checked() {
+    let a = pseudo_random::<f64>(20, 20, 8) + 4. * Array2::<f64>::eye(20);
+    let x = pseudo_random::<f64>(20, 3, 9);
+    for &uplo in &[Triangle::Lower, Triangle::Upper] {
+        let b = triangle(&a, uplo).dot(&x);
+        assert_close(&solve_triangular(&a, uplo, &b).unwrap(), &x, 1e-10);
+        let x_t = solve_triangular(&a.t().to_owned(), uplo, &b).unwrap();
+        assert_close(&solve_triangular(&a.t(), uplo, &b).unwrap(), &x_t, 1e-12);
+    }
+    let mut a = a;
+    a[[3, 3]] = 0.;
+    assert_eq!(solve_triangular(&a, Triangle::Lower, &x), Err(LinalgError::Singular));
+}
+
+#[test]
+fn qr_factors() {
+    for &(m, n) in &[(0, 0), (1, 1), (4, 4), (7, 3), (3, 7), (40, 25)] {
+        let k = m.min(n);
+        let a = pseudo_random::<f64>(m, n, 3);
+        for a in vec![a.clone(), a.reversed_axes().t().to_owned()] {
+            let (q, r) = qr(&a, QrMode::Reduced);
+            assert_eq!((q.dim(), r.dim()), ((m, k), (k, n)));
+            assert_close(&q.dot(&r), &a, 1e-12);
+            assert_close(&q.t().dot(&q), &Array::eye(k), 1e-12);
+            assert_close(&triangle(&r, Triangle::Upper), &r, 0.);
+
+            let (q, r) = qr(&a, QrMode::Complete);
+            assert_eq!((q.dim(), r.dim()), ((m, m), (m, n)));
+            assert_close(&q.dot(&r), &a, 1e-12);
+            assert_close(&q.t().dot(&q), &Array::eye(m), 1e-12);
+        }
+
+        let a = complex_pseudo_random(m, n, 4);
+        let qr = Qr::new(&a.t());
+        let (q, r) = (qr.q(QrMode::Complete), qr.r(QrMode::Complete));
+        assert_close(&q.dot(&r), &a.t().to_owned(), 1e-12);
+        assert_close(&conj_t(&q).dot(&q), &Array::eye(n), 1e-12);
+        assert!(r.diag().iter().all(|x| x.im == 0.));
+    }
+}
+
+#[test]
+fn lstsq_full_rank() {
+    // fit a quadratic to points that lie on it, and to points that do not
+    let x = Array::linspace(-1., 1., 9);
+    let a = Zip::from(&x).map_collect(|&x| [1., x, x * x]);
+    let a = Array2::from_shape_fn((9, 3), |(i, j)| a[i][j]);
+    let coef = arr1(&[0.5, -2., 3.]);
+    let y = a.dot(&coef);
+    let fit = lstsq(&a, &y).unwrap();
+    assert_eq!(fit.rank, 3);
+    assert_close(&fit.solution, &coef, 1e-12);
+    assert_close(&fit.residuals, &arr1(&[0.]), 1e-20);
+
+    let noise = pseudo_random::<f64>(9, 2, 5);
+    let b = stack![Axis(1), y, y] + &noise;
+    let fit = lstsq(&a.t().to_owned().reversed_axes(), &b).unwrap();
+    assert_eq!(fit.solution.dim(), (3, 2));
+    // the residual is orthogonal to the columns of A
+    let resid = &b - &a.dot(&fit.solution);
+    assert_close(&a.t().dot(&resid), &Array::zeros((3, 2)), 1e-12);
+    let sums = resid.mapv(|r| r * r).sum_axis(Axis(0));
+    assert_close(&fit.residuals, &sums, 1e-12);
+
+    // square and underdetermined systems have no residuals
+    let a = complex_pseudo_random(4, 6, 6);
+    let b = complex_pseudo_random(4, 1, 7).column(0).to_owned();
+    let fit = lstsq(&a, &b).unwrap();
+    assert_eq!(fit.rank, 4);
+    assert_eq!(fit.residuals.len(), 0);
+    assert_close(&a.dot(&fit.solution), &b, 1e-12);
+}
+
+#[test]
+fn lstsq_rank_deficient() {
+    let b = pseudo_random::<f64>(6, 2, 8);
+    let c = b.column(0).to_owned();
+    // the third column is the sum of the first two
+    let a = concatenate![Axis(1), b, b.sum_axis(Axis(1)).insert_axis(Axis(1))];
+    let fit = lstsq(&a, &c).unwrap();
+    assert_eq!(fit.rank, 2);
+    assert_eq!(fit.residuals.len(), 0);
+    assert_close(&a.dot(&fit.solution), &c, 1e-12);
+    assert_eq!(fit.solution.iter().filter(|&&x| x == 0.).count(), 1);
+
+    let fit = lstsq(&Array2::<f64>::zeros((3, 2)), &arr1(&[1., 2., 3.])).unwrap();
+    assert_eq!(fit.rank, 0);
+    assert_eq!(fit.solution, arr1(&[0., 0.]));
+}
+
+#[test]
+fn lstsq_errors() {
+    let a = pseudo_random::<f64>(4, 3, 9);
+    match lstsq(&a, &Array1::zeros(3)) {
+        Err(LinalgError::Shape(e)) => assert_eq!(e.kind(), ErrorKind::IncompatibleShape),
+        r => panic!("{:?}", r),
+    }
+    match lstsq(&a, &Array3::zeros((4, 1, 1))) {
+        Err(LinalgError::Shape(e)) => assert_eq!(e.kind(), ErrorKind::IncompatibleShape),
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn eigh_symmetric() {
+    for &n in &[0, 1, 2, 5, 33, 70] {
+        let b = pseudo_random::<f64>(n, n, 10);
+        let a = &b + &b.t();
+        for &uplo in &[Triangle::Lower, Triangle::Upper] {
+            // the other triangle is not read
+            let half = Array2::from_shape_fn((n, n), |(i, j)| match (uplo, i < j) {
+                (Triangle::Lower, true) | (Triangle::Upper, false) if i != j => f64::NAN,
+                _ => a[[i, j]],
+            });
+            let (w, v) = eigh(&half.t().to_owned().reversed_axes(), uplo).unwrap();
+            assert!(w.windows(2).into_iter().all(|w| w[0] <= w[1]));
+            assert_close(&a.dot(&v), &(&v * &w), 1e-12);
+            assert_close(&v.t().dot(&v), &Array::eye(n), 1e-12);
+            assert_close(&eigvalsh(&half, uplo).unwrap(), &w, 1e-12);
+        }
+    }
+}
+
+#[test]
+fn eigh_hermitian() {
+    for &n in &[1, 4, 40] {
+        let b = complex_pseudo_random(n, n, 11);
+        let a = &b + &conj_t(&b);
+        let (w, v) = eigh(&a, Triangle::Lower).unwrap();
+        let wc = w.mapv(|w| Complex64::new(w, 0.));
+        assert_close(&a.dot(&v), &(&v * &wc), 1e-12);
+        assert_close(&conj_t(&v).dot(&v), &Array::eye(n), 1e-12);
+        let (w_upper, _) = eigh(&a, Triangle::Upper).unwrap();
+        assert_close(&w_upper, &w, 1e-12);
+        // the trace is the sum of the eigenvalues
+        assert!((w.sum() - a.diag().sum().re).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn eigh_special() {
+    // repeated eigenvalues and a diagonal matrix
+    let a = Array2::from_diag(&arr1(&[3., 1., 3., 2.]));
+    let (w, v) = eigh(&a, Triangle::Lower).unwrap();
+    assert_eq!(w, arr1(&[1., 2., 3., 3.]));
+    assert_close(&a.dot(&v), &(&v * &w), 0.);
+    // widely different magnitudes
+    let a = arr2(&[[1e10, 1., 0.], [1., 1., 1e-10], [0., 1e-10, 1e-20f64]]);
+    let (w, v) = eigh(&a, Triangle::Lower).unwrap();
+    assert_close(&a.dot(&v), &(&v * &w), 1e-5);
+    assert!((w[2] - 1e10).abs() < 1e-5);
+    // many zero eigenvalues
+    let b = pseudo_random::<f64>(150, 2, 12);
+    let a = b.dot(&b.t());
+    let (w, v) = eigh(&a, Triangle::Upper).unwrap();
+    assert_close(&a.dot(&v), &(&v * &w), 1e-12);
+    assert_eq!(w.iter().filter(|w| w.abs() > 1e-12).count(), 2);
+
+    match eigh(&Array2::<f64>::zeros((2, 3)), Triangle::Lower) {
+        Err(LinalgError::NotSquare { rows: 2, cols: 3 }) => {}
+        r => panic!("{:?}", r),
+    }
+}
+
+fn check_svd<A: LinalgFloat>(a: &Array2<A>, tol: f64)
+where
+    A::Real: Into<f64>,
+{
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let conj_t = |x: &Array2<A>| x.t().mapv(A::conj);
+    let (_, s, _) = svd(a, SvdMode::ValuesOnly).unwrap();
+    assert_eq!(s.len(), k);
+    assert!(s.windows(2).into_iter().all(|s| s[0] >= s[1]));
+    for &(mode, u_cols, vt_rows) in &[(SvdMode::Thin, k, k), (SvdMode::Full, m, n)] {
+        let (u, s_vec, vt) = svd(a, mode).unwrap();
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        assert_eq!((u.dim(), vt.dim()), ((m, u_cols), (vt_rows, n)));
+        assert_close(&s_vec, &s, tol);
+        let mut sigma = Array2::zeros((u_cols, vt_rows));
+        sigma.diag_mut().assign(&s.mapv(A::from_real));
+        assert_close(&u.dot(&sigma).dot(&vt), a, tol);
+        assert_close(&conj_t(&u).dot(&u), &Array::eye(u_cols), tol);
+        assert_close(&vt.dot(&conj_t(&vt)), &Array::eye(vt_rows), tol);
+    }
+}
+
+#[test]
+fn svd_factors() {
+    for &(m, n) in &[(0, 0), (0, 3), (1, 1), (4, 4), (7, 3), (3, 7), (40, 25)] {
+        let a = pseudo_random::<f64>(m, n, 13);
+        check_svd(&a, 1e-12);
+        check_svd(&a.t().to_owned(), 1e-12);
+        check_svd(&complex_pseudo_random(m, n, 14), 1e-12);
+        check_svd(&pseudo_random::<f32>(m, n, 15), 1e-4);
+    }
+    // rank deficient, with zero columns and rows
+    let b = pseudo_random::<f64>(8, 2, 16);
+    let mut a = b.dot(&b.t());
+    a.column_mut(3).fill(0.);
+    a.row_mut(5).fill(0.);
+    check_svd(&a, 1e-12);
+    check_svd(&a.slice(s![.., ..5]).to_owned(), 1e-12);
+    check_svd(&Array2::<f64>::zeros((3, 2)), 0.);
+}
+
+#[test]
+fn svd_values() {
+    // the singular values of a Hermitian matrix are the absolute values of its
+    // eigenvalues
+    let b = complex_pseudo_random(20, 20, 17);
+    let a = &b + &conj_t(&b);
+    let mut w = eigvalsh(&a, Triangle::Lower).unwrap().mapv(f64::abs).to_vec();
+    w.sort_by(|x, y| y.partial_cmp(x).unwrap());
+    let (_, s, _) = svd(&a, SvdMode::ValuesOnly).unwrap();
+    assert_close(&s, &Array::from(w), 1e-12);
+
+    // widely different magnitudes
+    let d = arr1(&[1e10, 1., 1e-10, 1e-20]);
+    let q = Qr::new(&pseudo_random::<f64>(4, 4, 18)).q(QrMode::Complete);
+    let a = (&q * &d).dot(&q.t());
+    let (_, s, _) = svd(&a, SvdMode::ValuesOnly).unwrap();
+    assert!((s[0] - 1e10).abs() < 1e-4);
+    assert!((s[1] - 1.).abs() < 1e-6);
+}
+
+#[test]
+fn svd_derived() {
+    let a = pseudo_random::<f64>(6, 4, 19);
+    let (_, s, _) = svd(&a, SvdMode::ValuesOnly).unwrap();
+    assert_eq!(spectral_norm(&a).unwrap(), s[0]);
+    assert_eq!(nuclear_norm(&a).unwrap(), s.sum());
+    assert_eq!(cond(&a).unwrap(), s[0] / s[3]);
+    assert_eq!(matrix_rank(&a, None).unwrap(), 4);
+    assert_eq!(matrix_rank(&a, Some(s[1])).unwrap(), 1);
+
+    // the pseudo-inverse of a matrix with full column rank is a left inverse, and
+    // it gives the least squares solution
+    let a_pinv = pinv(&a, None).unwrap();
+    assert_close(&a_pinv.dot(&a), &Array::eye(4), 1e-12);
+    let b = pseudo_random::<f64>(6, 1, 20).column(0).to_owned();
+    assert_close(&a_pinv.dot(&b), &lstsq(&a, &b).unwrap().solution, 1e-12);
+
+    // Penrose conditions for a rank deficient complex matrix
+    let b = complex_pseudo_random(5, 2, 21);
+    let a = b.dot(&complex_pseudo_random(2, 7, 22));
+    assert_eq!(matrix_rank(&a, None).unwrap(), 2);
+    let x = pinv(&a, None).unwrap();
+    assert_eq!(x.dim(), (7, 5));
+    assert_close(&a.dot(&x).dot(&a), &a, 1e-12);
+    assert_close(&x.dot(&a).dot(&x), &x, 1e-12);
+    let ax = a.dot(&x);
+    assert_close(&conj_t(&ax), &ax, 1e-12);
+    let xa = x.dot(&a);
+    assert_close(&conj_t(&xa), &xa, 1e-12);
+
+    let singular = arr2(&[[1., 2.], [2., 4.]]);
+    assert_eq!(cond(&singular).unwrap(), f64::INFINITY);
+    assert_eq!(matrix_rank(&Array2::<f64>::zeros((2, 2)), None).unwrap(), 0);
+    assert_eq!(pinv(&Array2::<f64>::zeros((2, 3)), None).unwrap(), Array2::zeros((3, 2)));
+}
+
+#[test]
+fn matrix_power_cases() {
+    let a = pseudo_random::<f64>(5, 5, 23);
+    assert_eq!(matrix_power(&a, 0).unwrap(), Array::eye(5));
+    assert_eq!(matrix_power(&a, 1).unwrap(), a);
+    assert_close(&matrix_power(&a, 3).unwrap(), &a.dot(&a).dot(&a), 1e-12);
+    assert_close(&matrix_power(&a, -2).unwrap(), &inv(&a.dot(&a)).unwrap(), 1e-9);
+
+    let a = complex_pseudo_random(4, 4, 24);
+    let a5 = matrix_power(&a.t(), 5).unwrap();
+    assert_close(&a5, &a.t().dot(&a.t()).dot(&a.t()).dot(&a.t()).dot(&a.t()), 1e-12);
+
+    let singular = arr2(&[[1., 2.], [2., 4.]]);
+    assert_eq!(matrix_power(&singular, 2).unwrap(), arr2(&[[5., 10.], [10., 20.]]));
+    assert_eq!(matrix_power(&singular, -1), Err(LinalgError::Singular));
+    assert_eq!(
+        matrix_power(&Array2::<f64>::zeros((2, 3)), 2),
+        Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+#[test]
+fn expm_cases() {
+    assert_eq!(expm(&Array2::<f64>::zeros((3, 3))).unwrap(), Array::eye(3));
+    assert_eq!(expm(&Array2::<f64>::zeros((0, 0))).unwrap(), Array::eye(0));
+    // diagonal matrices, with norms that need the different approximants and scaling
+    for &x in &[1e-3, 0.1, 0.5, 1.5, 4., 30., -100f64] {
+        let d = arr1(&[x, -x / 2., x / 3.]);
+        let e = expm(&Array2::from_diag(&d)).unwrap();
+        let max_rel_err = Zip::from(e.diag())
+            .and(&d)
+            .fold(0., |acc: f64, &e, &d| acc.max((e - d.exp()).abs() / d.exp()));
+        assert!(max_rel_err < 1e-13, "{}: {:e}", x, max_rel_err);
+    }
+    // a rotation: exp(θ [[0, 1], [-1, 0]])
+    let theta = 2.5f64;
+    let e = expm(&arr2(&[[0., theta], [-theta, 0.]])).unwrap();
+    let (c, s) = (theta.cos(), theta.sin());
+    assert_close(&e, &arr2(&[[c, s], [-s, c]]), 1e-14);
+    // exp(iθ X) = cos θ I + i sin θ X, for the Pauli matrix X
+    let i = Complex64::i();
+    let x = arr2(&[[0. * i, 1. + 0. * i], [1. + 0. * i, 0. * i]]);
+    let e = expm(&(&x * (i * theta))).unwrap();
+    let expected = Array::eye(2) * Complex64::new(c, 0.) + &x * (i * s);
+    assert_close(&e, &expected, 1e-14);
+    // for a Hermitian A, exp(A) = V exp(Λ) Vᴴ
+    let b = complex_pseudo_random(10, 10, 25);
+    let a = &b + &conj_t(&b);
+    let (w, v) = eigh(&a, Triangle::Lower).unwrap();
+    let expected = (&v * &w.mapv(|w| Complex64::new(w.exp(), 0.))).dot(&conj_t(&v));
+    assert_close(&expm(&a).unwrap(), &expected, 1e-11);
+
+    assert!(expm(&arr2(&[[1., f64::INFINITY], [0., 1.]])).unwrap().iter().all(|x| x.is_nan()));
+    assert_eq!(
+        expm(&Array2::<f32>::zeros((3, 2))),
+        Err(LinalgError::NotSquare { rows: 3, cols: 2 })
+    );
+}
@@ -6,6 +6,9 @@
 )]
 #![cfg(feature = "std")]
 use ndarray::linalg::general_mat_mul;
+use ndarray::linalg::{general_outer_add, triangular_solve, Triangle};
+#[cfg(feature = "approx")]
+use ndarray::linalg::{symmetric_rank_k_update, triangular_mat_mul};
 use ndarray::prelude::*;
 use ndarray::{rcarr1, rcarr2};
 use ndarray::{Data, LinalgScalar};
@@ -649,6 +652,42 @@ fn gen_mat_mul() {
     }
 }
 
+// Large enough to use multiple threads with the rayon feature
+#[cfg(feature = "approx")]
+#[test]
+fn gen_mat_mul_large() {
+    let alpha = -2.3;
+    let beta = 3.14;
+    let sizes = vec![(300, 150, 200), (130, 140, 513), (513, 64, 1)];
+    for &(m, k, n) in &sizes {
+        // row step for a and c, column step for a and row step for b
+        for &(s1, s2) in &[(1, 1), (-1, 2)] {
+            let a = range_mat64(m, k * 2) / (m * k) as f64;
+            let b = range_mat64(k * 2, n) / (k * n) as f64;
+            let mut c = range_mat64(m, n);
+            let mut answer = c.clone();
+
+            {
+                let a = a.slice(s![..;s1, ..;s2]);
+                let b = b.slice(s![..;s2, ..]);
+                let mut cv = c.slice_mut(s![..;s1, ..]);
+                let answer_part = alpha * reference_mat_mul(&a, &b) + beta * &cv;
+                answer.slice_mut(s![..;s1, ..]).assign(&answer_part);
+
+                general_mat_mul(alpha, &a, &b, beta, &mut cv);
+            }
+            approx::assert_relative_eq!(c, answer, epsilon = 1e-12, max_relative = 1e-7);
+        }
+
+        // f32 with an f-order rhs
+        let a = range_mat(m, k) / (m * k) as f32;
+        let b = range_mat(n, k);
+        let b = b.t();
+        approx::assert_relative_eq!(a.dot(&b), reference_mat_mul(&a, &b),
+                                    epsilon = 1e-4, max_relative = 1e-4);
+    }
+}
+
 // Test y = A x where A is f-order
 #[cfg(feature = "approx")]
 #[test]
@@ -693,6 +732,333 @@ fn gen_mat_mul_i32() {
     }
 }
 
+// Sizes that span several blocks of the blocked kernel, with strided and transposed
+// operands
+#[test]
+#[cfg_attr(miri, ignore)]
+fn gen_mat_mul_i64_blocked() {
+    let sizes = vec![
+        (70, 260, 9),
+        (3, 300, 530),
+        (130, 40, 70),
+        (65, 257, 66),
+    ];
+    for &(m, k, n) in &sizes {
+        let a = Array::from_shape_fn((m, 2 * k), |(i, j)| ((i * 7 + j) % 13) as i64 - 6);
+        let b = Array::from_shape_fn((n, k), |(i, j)| ((i * 3 + j) % 11) as i64 - 5);
+        let a = a.slice(s![.., ..;2]);
+        let b = b.t();
+        let mut c = Array::from_shape_fn((m, n), |(i, j)| (i + j) as i64);
+
+        let answer = 3 * reference_mat_mul(&a, &b) - &c;
+        assert_eq!(a.dot(&b), reference_mat_mul(&a, &b));
+        general_mat_mul(3, &a, &b, -1, &mut c);
+        assert_eq!(&c, &answer);
+
+        let mut c = Array::from_elem((n, m).f(), 1);
+        general_mat_mul(1, &b.t(), &a.t(), 0, &mut c);
+        assert_eq!(c, reference_mat_mul(&a, &b).t());
+    }
+}
+
+// small integer values, so that the results are exact in any order of summation
+fn complex_mat<T: From<i16>>(m: Ix, n: Ix) -> Array2<num_complex::Complex<T>> {
+    Array::from_shape_fn((m, n), |(i, j)| {
+        let x = ((i * n + j) % 7) as i16;
+        num_complex::Complex::new(T::from(x - 3), T::from(2 - x % 5))
+    })
+}
+
+#[test]
+fn complex_mat_mul() {
+    use ndarray::linalg::general_mat_vec_mul;
+    use num_complex::{Complex32, Complex64};
+
+    macro_rules! test_complex {
+        ($ty:ty, $re:ty) => {
+            let alpha = <$ty>::new(2., -1.);
+            let beta = <$ty>::new(-0.5, 3.);
+            for &(m, k, n) in &[(4, 4, 4), (17, 15, 16), (33, 8, 65), (1, 9, 1), (0, 3, 2)] {
+                for &(s1, s2) in &[(1, 1), (2, 1), (-1, 2), (-2, -1)] {
+                    let a0 = complex_mat::<$re>(m * 2, k * 2);
+                    let b0 = complex_mat::<$re>(k * 2, n * 2);
+                    let a = a0.slice(s![..;s1, ..;s2]).slice_move(s![..m as isize, ..k as isize]);
+                    let b = b0.slice(s![..;s2, ..;s1]).slice_move(s![..k as isize, ..n as isize]);
+                    let b_f = b.as_standard_layout().reversed_axes().to_owned();
+                    let b_f = b_f.t();
+                    let answer = reference_mat_mul(&a, &b);
+                    assert_eq!(a.dot(&b), answer);
+                    assert_eq!(a.t().to_owned().t().dot(&b_f), answer);
+
+                    let mut c = complex_mat::<$re>(m, n);
+                    let c_answer = answer.mapv(|x| x * alpha) + c.mapv(|x| x * beta);
+                    general_mat_mul(alpha, &a, &b, beta, &mut c);
+                    assert_eq!(c, c_answer);
+
+                    if n > 0 {
+                        let x = b.column(0);
+                        let mut y = complex_mat::<$re>(m, 1).remove_axis(Axis(1));
+                        let y_answer =
+                            answer.column(0).mapv(|x| x * alpha) + y.slice(s![..;-1]).mapv(|x| x * beta);
+                        general_mat_vec_mul(alpha, &a, &x, beta, &mut y.slice_mut(s![..;-1]));
+                        assert_eq!(y.slice(s![..;-1]), y_answer);
+                        assert_eq!(a.dot(&x), answer.column(0));
+                        assert_eq!(x.dot(&x), x.iter().fold(<$ty>::new(0., 0.), |s, &x| s + x * x));
+                    }
+                }
+            }
+        };
+    }
+    test_complex!(Complex32, f32);
+    test_complex!(Complex64, f64);
+}
+
+#[test]
+fn complex_mat_mul_conj() {
+    use ndarray::linalg::{general_mat_mul_conj, general_mat_vec_mul_conj, Conj};
+    use num_complex::{Complex32, Complex64};
+
+    macro_rules! test_conj {
+        ($ty:ty, $re:ty) => {
+            let alpha = <$ty>::new(2., -1.);
+            let beta = <$ty>::new(-0.5, 3.);
+            let op = |a: ArrayView2<$ty>, conj| match conj {
+                Conj::No => a.to_owned(),
+                Conj::Yes => a.mapv(|x| x.conj()),
+            };
+            for &(m, k, n) in &[(4, 4, 4), (17, 15, 16), (33, 8, 65), (1, 9, 1), (40, 33, 70)] {
+                let a = complex_mat::<$re>(m, k);
+                let b = complex_mat::<$re>(k, n);
+                let a_t = complex_mat::<$re>(k, m);
+                let b_t = complex_mat::<$re>(n, k);
+                let c0 = complex_mat::<$re>(m, n);
+                let conjs = [(Conj::No, Conj::Yes), (Conj::Yes, Conj::No), (Conj::Yes, Conj::Yes)];
+                for &(conj_a, conj_b) in &conjs {
+                    // c- and f-order operands, and transposed views of them
+                    let a_f = a.t().as_standard_layout().into_owned().reversed_axes();
+                    let b_f = b.t().as_standard_layout().into_owned().reversed_axes();
+                    let lhs = [a.view(), a_f.view(), a_t.t()];
+                    let rhs = [b.view(), b_f.view(), b_t.t()];
+                    for lhs in &lhs {
+                        for rhs in &rhs {
+                            let answer = op(lhs.view(), conj_a).dot(&op(rhs.view(), conj_b));
+                            let answer = answer.mapv(|x| x * alpha) + c0.mapv(|x| x * beta);
+                            let mut c = c0.clone();
+                            general_mat_mul_conj(alpha, lhs, conj_a, rhs, conj_b, beta, &mut c);
+                            assert_eq!(c, answer);
+                        }
+                    }
+
+                    for lhs in &lhs {
+                        let x = b.column(0);
+                        let x_op = op(x.insert_axis(Axis(1)), conj_b).remove_axis(Axis(1));
+                        let y0 = c0.column(0);
+                        let answer = op(lhs.view(), conj_a).dot(&x_op).mapv(|x| x * alpha)
+                            + y0.mapv(|x| x * beta);
+                        let mut y = y0.to_owned();
+                        general_mat_vec_mul_conj(alpha, lhs, conj_a, &x, conj_b, beta, &mut y);
+                        assert_eq!(y, answer);
+                    }
+                }
+            }
+        };
+    }
+    test_conj!(Complex32, f32);
+    test_conj!(Complex64, f64);
+
+    // conjugation has no effect for the real types
+    let a = range_mat64(5, 4);
+    let mut c = Array2::zeros((4, 4));
+    general_mat_mul_conj(1., &a.t(), Conj::Yes, &a, Conj::Yes, 0., &mut c);
+    assert_eq!(c, a.t().dot(&a));
+}
+
+// The triangle `uplo` of `a`, with zeros elsewhere
+#[cfg(feature = "approx")]
+fn triangle_of<A: LinalgScalar>(a: &Array2<A>, uplo: Triangle) -> Array2<A> {
+    Array::from_shape_fn(a.dim(), |(i, j)| match uplo {
+        Triangle::Upper if i <= j => a[[i, j]],
+        Triangle::Lower if i >= j => a[[i, j]],
+        _ => A::zero(),
+    })
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn outer_add() {
+    for &(m, n) in &[(0, 3), (1, 1), (4, 7), (17, 15), (40, 33)] {
+        for &rev in &[false, true] {
+            let x = range1_mat64(2 * m);
+            let y = range1_mat64(n);
+            let x = x.slice(s![..;-2]);
+            let mut a = if rev {
+                range_mat64(n, m).reversed_axes()
+            } else {
+                range_mat64(m, n)
+            };
+            let x2 = x.to_owned().into_shape((m, 1)).unwrap();
+            let y2 = y.clone().into_shape((1, n)).unwrap();
+            let answer = &a - 0.5 * reference_mat_mul(&x2, &y2);
+            general_outer_add(-0.5, &x, &y, &mut a);
+            approx::assert_relative_eq!(a, answer, epsilon = 1e-12, max_relative = 1e-7);
+        }
+    }
+
+    let mut a = range_i32(3, 4);
+    general_outer_add(2, &aview1(&[1, 0, -1]), &aview1(&[1, 2, 3, 4]), &mut a);
+    assert_eq!(a, arr2(&[[2, 5, 8, 11], [4, 5, 6, 7], [6, 5, 4, 3]]));
+}
+
+#[test]
+#[should_panic]
+fn outer_add_shape_mismatch() {
+    let mut a = Array2::<f64>::zeros((3, 4));
+    general_outer_add(1., &Array::zeros(4), &Array::zeros(3), &mut a);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn syrk() {
+    for &(n, k) in &[(1, 1), (4, 0), (4, 7), (17, 15), (33, 40)] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            for &rev in &[false, true] {
+                let a = range_mat64(n, k) / (n * k) as f64;
+                let mut c = if rev {
+                    range_mat64(n, n).reversed_axes()
+                } else {
+                    range_mat64(n, n)
+                };
+                let c0 = c.clone();
+                symmetric_rank_k_update(uplo, 2., &a, -1., &mut c);
+                let full = 2. * reference_mat_mul(&a, &a.t()) - &c0;
+                let other = uplo_other(uplo);
+                approx::assert_relative_eq!(
+                    triangle_of(&c, uplo),
+                    triangle_of(&full, uplo),
+                    epsilon = 1e-12,
+                    max_relative = 1e-7
+                );
+                // the diagonal is in both triangles
+                assert_eq!(
+                    triangle_of(&c, other) - Array2::from_diag(&c.diag()),
+                    triangle_of(&c0, other) - Array2::from_diag(&c0.diag())
+                );
+            }
+        }
+    }
+
+    // beta = 0 overwrites the triangle
+    let a = range_i32(3, 2);
+    let mut c = Array::from_elem((3, 3), -1);
+    symmetric_rank_k_update(Triangle::Upper, 1, &a, 0, &mut c.view_mut().reversed_axes());
+    assert_eq!(c, arr2(&[[1, -1, -1], [3, 13, -1], [5, 23, 41]]));
+}
+
+#[cfg(feature = "approx")]
+fn uplo_other(uplo: Triangle) -> Triangle {
+    match uplo {
+        Triangle::Upper => Triangle::Lower,
+        Triangle::Lower => Triangle::Upper,
+    }
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn triangular_mul() {
+    for &(m, n) in &[(1, 1), (4, 7), (7, 4), (17, 15), (40, 33)] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            for &(rev_a, rev_b) in &[(false, false), (true, false), (false, true)] {
+                let mut a = range_mat64(m, m);
+                if rev_a {
+                    a = a.reversed_axes();
+                }
+                let mut b = range_mat64(n, m).reversed_axes();
+                if rev_b {
+                    b = b.as_standard_layout().into_owned();
+                }
+                let answer = 0.5 * reference_mat_mul(&triangle_of(&a, uplo), &b);
+                triangular_mat_mul(uplo, 0.5, &a, &mut b);
+                approx::assert_relative_eq!(b, answer, epsilon = 1e-12, max_relative = 1e-7);
+            }
+        }
+    }
+
+    let a = range_i32(3, 3);
+    let mut b = range_i32(3, 2);
+    triangular_mat_mul(Triangle::Upper, 1, &a, &mut b);
+    assert_eq!(b, reference_mat_mul(&triangle_of(&a, Triangle::Upper), &range_i32(3, 2)));
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn triangular_solve_mat_vec() {
+    for &m in &[0, 1, 4, 17, 40] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            for &rev in &[false, true] {
+                // diagonally dominant, so that the system is well-conditioned
+                let mut a = range_mat64(m, m) / (m * m) as f64 + Array2::<f64>::eye(m);
+                if rev {
+                    a = a.reversed_axes();
+                }
+                let a_tri = triangle_of(&a, uplo);
+
+                let x = range1_mat64(m);
+                let mut b = a_tri.dot(&x);
+                triangular_solve(uplo, &a, &mut b);
+                approx::assert_relative_eq!(b, x, epsilon = 1e-9, max_relative = 1e-9);
+
+                let x = range_mat64(m, 5);
+                let mut b = a_tri.dot(&x).reversed_axes();
+                triangular_solve(uplo, &a, &mut b.view_mut().reversed_axes());
+                approx::assert_relative_eq!(b.t(), x, epsilon = 1e-9, max_relative = 1e-9);
+            }
+        }
+    }
+
+    let a = arr2(&[[2, 0], [1, 3]]);
+    let mut b = arr2(&[[4, 2], [8, 7]]);
+    triangular_solve(Triangle::Lower, &a, &mut b);
+    assert_eq!(b, arr2(&[[2, 1], [2, 2]]));
+}
+
+#[test]
+#[should_panic]
+fn triangular_solve_3d() {
+    let a = Array2::<f64>::eye(2);
+    triangular_solve(Triangle::Lower, &a, &mut Array::zeros((2, 2, 2)));
+}
+
+#[test]
+fn dot_ixdyn() {
+    use ndarray::ErrorKind;
+
+    let a = range_i32(3, 4);
+    let b = range_i32(4, 2);
+    let x = Array::from_iter(0..4);
+    let y = Array::from_iter(0..3);
+    let (ad, bd) = (a.clone().into_dyn(), b.clone().into_dyn());
+    let (xd, yd) = (x.clone().into_dyn(), y.clone().into_dyn());
+
+    assert_eq!(ad.dot(&bd).unwrap(), a.dot(&b).into_dyn());
+    assert_eq!(ad.dot(&xd).unwrap(), a.dot(&x).into_dyn());
+    assert_eq!(yd.dot(&ad).unwrap(), y.dot(&a).into_dyn());
+    assert_eq!(xd.dot(&xd).unwrap(), arr0(x.dot(&x)).into_dyn());
+    assert_eq!(ad.t().dot(&yd.view()).unwrap(), a.t().dot(&y).into_dyn());
+
+    let err = ad.dot(&yd).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(err.operation(), Some("dot"));
+    assert_eq!(err.expected_shape(), Some(&[4][..]));
+    assert_eq!(err.actual_shape(), Some(&[3][..]));
+    assert_eq!(bd.dot(&ad).unwrap_err().expected_shape(), Some(&[2, 4][..]));
+
+    let err = ad.dot(&Array::zeros((4, 2, 1)).into_dyn()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(err.index(), Some(1));
+    let err = arr0(1).into_dyn().dot(&xd).unwrap_err();
+    assert_eq!(err.index(), Some(0));
+}
+
 #[cfg(feature = "approx")]
 #[test]
 fn gen_mat_vec_mul() {
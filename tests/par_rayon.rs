@@ -2,6 +2,7 @@
 
 use ndarray::parallel::prelude::*;
 use ndarray::prelude::*;
+use ndarray::Zip;
 
 const M: usize = 1024 * 10;
 const N: usize = 100;
@@ -86,3 +87,106 @@ fn test_axis_chunks_iter_mut() {
     println!("{:?}", a.slice(s![..10, ..5]));
     assert_abs_diff_eq!(a, b, epsilon = 0.001);
 }
+
+#[test]
+fn test_par_axis_reductions() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| ((i * 7 + j * 3) % 13) as f64);
+    for &axis in &[Axis(0), Axis(1)] {
+        assert_eq!(a.par_sum_axis(axis), a.sum_axis(axis));
+        assert_eq!(a.par_mean_axis(axis), a.mean_axis(axis));
+        assert_eq!(a.par_map_axis(axis, |lane| lane[0]), a.map_axis(axis, |lane| lane[0]));
+        assert_eq!(a.par_fold_axis(axis, 0., |&acc, &x| f64::max(acc, x)),
+                   a.fold_axis(axis, 0., |&acc, &x| f64::max(acc, x)));
+        let var = a.par_var_axis(axis, 1.);
+        Zip::from(&var).and(&a.var_axis(axis, 1.)).for_each(|x, y| assert!((x - y).abs() < 1e-9));
+    }
+    // lanes are folded in their logical order, also with negative strides
+    let rev = a.slice(s![..;-1, ..;-1]);
+    for &axis in &[Axis(0), Axis(1)] {
+        let first = |&acc: &Option<f64>, &x: &f64| Some(acc.unwrap_or(x));
+        assert_eq!(rev.par_fold_axis(axis, None, first), rev.fold_axis(axis, None, first));
+        assert_eq!(rev.par_sum_axis(axis), rev.sum_axis(axis));
+    }
+    let c = Array::from_shape_fn((7, 30, 11), |(i, j, k)| (i * j + k) as f64 / 7.);
+    for &axis in &[Axis(0), Axis(1), Axis(2)] {
+        assert_eq!(c.par_sum_axis(axis), c.sum_axis(axis));
+        assert_eq!(c.t().par_sum_axis(axis), c.t().sum_axis(axis));
+    }
+    let d = Array::linspace(0., 1.0f64, M).mapv(|x| (x * 100.).sin() * 10.);
+    assert_eq!(d.par_sum_axis(Axis(0)), d.sum_axis(Axis(0)));
+    assert_eq!(d.slice(s![..;-3]).par_sum_axis(Axis(0)), d.slice(s![..;-3]).sum_axis(Axis(0)));
+    assert_eq!(d.par_mean_axis(Axis(0)), d.mean_axis(Axis(0)));
+    assert_eq!(a.slice(s![..0, ..]).par_mean_axis(Axis(0)), None);
+    assert_eq!(a.slice(s![..0, ..]).par_sum_axis(Axis(0)), Array::zeros(N));
+}
+
+#[test]
+fn test_par_sum() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| ((i * 7 + j * 3) % 13) as i64);
+    assert_eq!(a.par_sum(), a.sum());
+    assert_eq!(a.t().par_sum(), a.sum());
+    assert_eq!(a.slice(s![..;3, 1..]).par_sum(), a.slice(s![..;3, 1..]).sum());
+
+    // floating point sums are deterministic
+    let b = Array::linspace(0., 1.0f64, M * N).into_shape((M, N)).unwrap();
+    let sum = b.par_sum();
+    for _ in 0..10 {
+        assert_eq!(b.par_sum(), sum);
+    }
+    assert!((sum - b.sum()).abs() < 1e-6);
+}
+
+#[test]
+fn test_par_windows_and_chunks() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i + j) as f64);
+    let sums: Vec<f64> = a.windows((2, 3)).into_par_iter().map(|w| w.sum()).collect();
+    assert_eq!(sums.len(), (M - 1) * (N - 2));
+    let total: f64 = sums.iter().sum();
+    assert_eq!(total, a.windows((2, 3)).into_iter().map(|w| w.sum()).sum());
+
+    let count = a.exact_chunks((CHUNK_SIZE, 30)).into_par_iter().count();
+    assert_eq!(count, (M / CHUNK_SIZE) * (N / 30));
+
+    let mut b = Array::zeros((M, N));
+    b.exact_chunks_mut((CHUNK_SIZE, 30))
+        .into_par_iter()
+        .for_each(|mut chunk| chunk.fill(1.));
+    assert_eq!(b.sum(), ((M / CHUNK_SIZE) * CHUNK_SIZE * 90) as f64);
+    assert_eq!(b.column(N - 1).sum(), 0.);
+}
+
+#[test]
+fn test_par_chunks() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i + j) as f64);
+    let sum: f64 = a.chunks((CHUNK_SIZE, 7)).into_par_iter().map(|c| c.sum()).sum();
+    assert_eq!(sum, a.sum());
+
+    let mut b = Array::<f64, _>::zeros((M, N));
+    b.chunks_mut((CHUNK_SIZE, 7))
+        .into_par_iter()
+        .for_each(|mut chunk| {
+            let n = chunk.len() as f64;
+            chunk.fill(n)
+        });
+    assert_eq!(b[[0, 0]], (CHUNK_SIZE * 7) as f64);
+    assert_eq!(b[[M - 1, N - 1]], ((M % CHUNK_SIZE) * (N % 7)) as f64);
+
+    let c = a.view().into_dyn();
+    let sum: f64 = c.chunks(IxDyn(&[CHUNK_SIZE, 7])).into_par_iter().map(|c| c.sum()).sum();
+    assert_eq!(sum, a.sum());
+}
+
+#[test]
+fn test_par_lanes() {
+    let mut a = Array::from_shape_fn((M, N), |(i, j)| (i + j) as f64);
+    let max: f64 = a.lanes(Axis(1))
+        .into_par_iter()
+        .map(|lane| lane.sum())
+        .reduce(|| 0., f64::max);
+    assert_eq!(max, a.row(M - 1).sum());
+
+    a.lanes_mut(Axis(0))
+        .into_par_iter()
+        .for_each(|mut lane| lane.fill(lane[0]));
+    assert_eq!(a.row(M - 1), a.row(0));
+}
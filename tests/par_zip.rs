@@ -2,7 +2,7 @@
 
 use ndarray::prelude::*;
 
-use ndarray::Zip;
+use ndarray::{FnProducer, Zip};
 
 const M: usize = 1024 * 10;
 const N: usize = 100;
@@ -140,3 +140,76 @@ fn test_zip_assign_into() {
 
     assert_abs_diff_eq!(a, &b + &c, epsilon = 1e-6);
 }
+
+#[test]
+fn test_zip_fn_producer() {
+    let mut a = Array2::<f64>::zeros((M, N));
+    let weights: Vec<f64> = (0..N).map(|j| j as f64).collect();
+    let p = FnProducer::new((M, N), |(i, j)| i as f64 + weights[j]);
+
+    Zip::from(&mut a).and(p).par_for_each(|x, y| *x = y);
+
+    for ((i, j), elt) in a.indexed_iter() {
+        assert_eq!(*elt, i as f64 + j as f64);
+    }
+}
+
+#[test]
+fn test_zip_many_producers() {
+    let a = Array2::from_shape_fn((M, N), |(i, j)| (i + j) as f64);
+    let mut out = Array2::zeros((M, N));
+
+    Zip::from(&mut out).and(&a).and(&a).and(&a).and(&a).and(&a)
+        .and(&a).and(&a).and(&a).and(&a).and(&a)
+        .par_for_each(|o, &b, &c, &d, &e, &f, &g, &h, &i, &j, &k| {
+            *o = b + c + d + e + f + g + h + i + j + k;
+        });
+    assert_eq!(out, &a * 10.);
+
+    let diff = Zip::from(&a).and(&a).and(&a).and(&a).and(&a).and(&a)
+        .and(&a).and(&a).and(&a).and(&a).and(&out)
+        .par_map_collect(|&b, &c, &d, &e, &f, &g, &h, &i, &j, &k, &o| {
+            b + c + d + e + f + g + h + i + j + k - o
+        });
+    assert!(diff.iter().all(|&x| x == 0.));
+}
+
+#[test]
+fn test_zip_par_fold() {
+    let a = Array2::from_shape_fn((M, N), |(i, j)| ((i + j) % 7) as f64);
+    let b = Array2::from_shape_fn((M, N).f(), |(i, j)| ((i * j) % 5) as f64);
+
+    let (dot, count) = Zip::from(&a).and(&b).par_fold(
+        || (0., 0),
+        |(dot, count), &x, &y| (dot + x * y, count + 1),
+        |(d1, c1), (d2, c2)| (d1 + d2, c1 + c2),
+    );
+    assert_eq!(dot, Zip::from(&a).and(&b).fold(0., |acc, &x, &y| acc + x * y));
+    assert_eq!(count, M * N);
+
+    let dot2 = Zip::from(&a).and(&b).par_map_reduce(|&x, &y| x * y, || 0., |x, y| x + y);
+    assert_eq!(dot2, dot);
+    let max = Zip::from(&a).par_map_reduce(|&x| x, || f64::NEG_INFINITY, f64::max);
+    assert_eq!(max, 6.);
+}
+
+#[test]
+fn test_zip_lanes_softmax() {
+    let a = Array2::from_shape_fn((M, N), |(i, j)| ((i * j) % 11) as f64);
+    let mut s = Array2::zeros((M, N));
+    let axis = a.min_stride_axis();
+
+    Zip::lanes(axis, &a)
+        .and_lanes_mut(axis, &mut s)
+        .par_for_each(|a, mut s| {
+            let max = a.fold(f64::NEG_INFINITY, |m, &x| m.max(x));
+            s.zip_mut_with(&a, |s, &a| *s = (a - max).exp());
+            let sum = s.sum();
+            s /= sum;
+        });
+
+    for row in s.rows() {
+        assert!((row.sum() - 1.).abs() < 1e-12);
+    }
+    assert_eq!(s[[0, 0]], 1. / N as f64);
+}
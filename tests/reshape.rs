@@ -27,6 +27,23 @@ fn reshape_error1() {
     let _u = v.into_shape((2, 5)).unwrap();
 }
 
+#[test]
+fn reshape_error_context() {
+    let data = [1, 2, 3, 4, 5, 6, 7, 8];
+    let err = aview1(&data).into_shape((2, 5)).unwrap_err();
+    assert_eq!(err.operation(), Some("into_shape"));
+    assert_eq!(err.expected_shape(), Some(&[8][..]));
+    assert_eq!(err.actual_shape(), Some(&[2, 5][..]));
+
+    let err = Array::from_shape_vec((3, 3), data.to_vec()).unwrap_err();
+    assert_eq!(err.operation(), Some("from_shape_vec"));
+    assert_eq!(err.expected_shape(), Some(&[3, 3][..]));
+    assert_eq!(err.actual_shape(), Some(&[8][..]));
+    assert_eq!(err.to_string(),
+        "ShapeError/OutOfBounds: out of bounds indexing (operation: from_shape_vec, \
+         expected shape: [3, 3], actual shape: [8])");
+}
+
 #[test]
 #[should_panic(expected = "IncompatibleLayout")]
 fn reshape_error2() {
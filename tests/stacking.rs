@@ -23,7 +23,13 @@ fn concatenating() {
     assert_eq!(d, aview2(&[[2.], [2.], [9.], [9.]]));
 
     let res = ndarray::concatenate(Axis(1), &[a.view(), c.view()]);
-    assert_eq!(res.unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(err.operation(), Some("concatenate"));
+    assert_eq!(err.expected_shape(), Some(&[2, 2][..]));
+    assert_eq!(err.actual_shape(), Some(&[6, 2][..]));
+    assert_eq!(err.axis(), Some(Axis(1)));
+    assert_eq!(err.index(), Some(1));
 
     let res = ndarray::concatenate(Axis(2), &[a.view(), c.view()]);
     assert_eq!(res.unwrap_err().kind(), ErrorKind::OutOfBounds);
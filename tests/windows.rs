@@ -152,3 +152,66 @@ fn test_window_neg_stride() {
         answer.iter()
     );
 }
+
+#[test]
+fn test_window_stride_zip() {
+    let a = Array::from_iter(0..120).into_shape((4, 5, 6)).unwrap();
+
+    for x in 1..4 {
+        for sx in 1..4 {
+            for sy in 1..3 {
+                let windows = a.windows_with_stride((x, 2, 3), (sx, sy, 3));
+                let shape = Zip::from(windows.clone()).map_collect(|_| ()).dim();
+                assert_eq!(shape, ((4 - x) / sx + 1, 3 / sy + 1, 2));
+                Zip::indexed(windows).for_each(|(i, j, k), window| {
+                    let (i, j, k) = ((i * sx) as isize, (j * sy) as isize, (k * 3) as isize);
+                    assert_eq!(window, a.slice(s![i..i + x as isize, j..j + 2, k..k + 3]));
+                })
+            }
+        }
+    }
+}
+
+#[test]
+fn test_window_dilated_zip() {
+    let a = Array::from_iter(0..120).into_shape((4, 5, 6)).unwrap();
+
+    for dx in 1..4 {
+        for dy in 1..3 {
+            let windows = a.windows_dilated((2, 3, 1), (dx, dy, 4));
+            let shape = Zip::from(windows.clone()).map_collect(|_| ()).dim();
+            assert_eq!(shape, (4 - dx, 5 - 2 * dy, 6));
+            Zip::indexed(windows).for_each(|(i, j, k), window| {
+                let (i, j, k) = (i as isize, j as isize, k as isize);
+                let (dx, dy) = (dx as isize, dy as isize);
+                assert_eq!(window, a.slice(s![i..i + dx + 1;dx, j..j + 2 * dy + 1;dy, k..k + 1]));
+            })
+        }
+    }
+}
+
+#[test]
+fn test_window_stride_dilated_edge_cases() {
+    let a = Array::from_iter(0..10);
+
+    // windows that do not fit produce nothing
+    assert_eq!(a.windows_dilated(4, 4).into_iter().count(), 0);
+    assert_eq!(a.windows_with_stride(11, 1).into_iter().count(), 0);
+    // the last window ends where the array ends
+    itertools::assert_equal(
+        a.windows_with_stride(4, 3),
+        vec![aview1(&[0, 1, 2, 3]), aview1(&[3, 4, 5, 6]), aview1(&[6, 7, 8, 9])],
+    );
+    // negative strides
+    itertools::assert_equal(
+        a.slice(s![..;-1]).windows_dilated(2, 3),
+        (0..7).rev().map(|i| arr1(&[i + 3, i])),
+    );
+}
+
+#[test]
+#[should_panic]
+fn windows_zero_stride() {
+    let a = Array::from_iter(0..10);
+    a.windows_with_stride(2, 0);
+}
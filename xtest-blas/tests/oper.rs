@@ -1,6 +1,7 @@
 extern crate approx;
 extern crate defmac;
 extern crate ndarray;
+extern crate num_complex;
 extern crate num_traits;
 extern crate blas_src;
 
@@ -8,10 +9,15 @@ use ndarray::prelude::*;
 
 use ndarray::linalg::general_mat_mul;
 use ndarray::linalg::general_mat_vec_mul;
+use ndarray::linalg::{general_mat_mul_conj, general_mat_vec_mul_conj, Conj};
+use ndarray::linalg::{
+    general_outer_add, symmetric_rank_k_update, triangular_mat_mul, triangular_solve, Triangle,
+};
 use ndarray::{Data, Ix, LinalgScalar};
 
 use approx::assert_relative_eq;
 use defmac::defmac;
+use num_complex::{Complex, Complex32, Complex64};
 
 #[test]
 fn mat_vec_product_1d() {
@@ -319,3 +325,172 @@ fn vec_mat_mul() {
         }
     }
 }
+
+// Small integer values, so that the products are exact in any order of summation
+fn complex_mat<T: From<i16>>(m: Ix, n: Ix) -> Array2<Complex<T>> {
+    Array::from_shape_fn((m, n), |(i, j)| {
+        let x = ((i * n + j) % 7) as i16;
+        Complex::new(T::from(x - 3), T::from(2 - x % 5))
+    })
+}
+
+// The same matrix in f-order
+fn f_order<A: Clone>(a: &Array2<A>) -> Array2<A> {
+    a.t().as_standard_layout().into_owned().reversed_axes()
+}
+
+// Complex products with c- and f-order and transposed operands, which select
+// the layout and transpose arguments passed to cgemm/zgemm, cgemv/zgemv and dotu
+#[test]
+fn complex_mat_mul() {
+    macro_rules! test_complex {
+        ($ty:ty, $re:ty) => {
+            let alpha = <$ty>::new(2., -1.);
+            let beta = <$ty>::new(-0.5, 3.);
+            for &(m, k, n) in &[(4, 4, 4), (17, 15, 16), (33, 8, 65), (1, 9, 1), (64, 64, 1)] {
+                let a = complex_mat::<$re>(m, k);
+                let b = complex_mat::<$re>(k, n);
+                let answer = reference_mat_mul(&a, &b);
+                let (af, bf) = (f_order(&a), f_order(&b));
+                let (at, bt) = (complex_mat::<$re>(k, m), complex_mat::<$re>(n, k));
+                let answer_t = reference_mat_mul(&at.t(), &bt.t());
+                for &(fa, fb) in &[(false, false), (true, false), (false, true), (true, true)] {
+                    let a = if fa { af.view() } else { a.view() };
+                    let b = if fb { bf.view() } else { b.view() };
+                    assert_eq!(a.dot(&b), answer);
+
+                    let at = if fa { f_order(&at) } else { at.clone() };
+                    let bt = if fb { f_order(&bt) } else { bt.clone() };
+                    assert_eq!(at.t().dot(&bt.t()), answer_t);
+
+                    for &fc in &[false, true] {
+                        let c0 = complex_mat::<$re>(m, n);
+                        let mut c = if fc { f_order(&c0) } else { c0.clone() };
+                        let c_answer = answer.mapv(|x| x * alpha) + c0.mapv(|x| x * beta);
+                        general_mat_mul(alpha, &a, &b, beta, &mut c);
+                        assert_eq!(c, c_answer);
+                    }
+
+                    let x = b.column(0);
+                    let y0 = complex_mat::<$re>(m, 1).remove_axis(Axis(1));
+                    let mut y = y0.clone();
+                    let y_answer = answer.column(0).mapv(|x| x * alpha) + y0.mapv(|x| x * beta);
+                    general_mat_vec_mul(alpha, &a, &x, beta, &mut y);
+                    assert_eq!(y, y_answer);
+                    assert_eq!(a.dot(&x), answer.column(0));
+                    assert_eq!(a.t().dot(&a.column(0)), reference_vec_mat_mul(&a.column(0), &a));
+                    let dot_answer = x.iter().zip(x.iter().rev())
+                        .fold(<$ty>::new(0., 0.), |s, (&x, &y)| s + x * y);
+                    assert_eq!(x.dot(&x.slice(s![..;-1])), dot_answer);
+                }
+            }
+        };
+    }
+    test_complex!(Complex32, f32);
+    test_complex!(Complex64, f64);
+}
+
+// Conjugated complex operands; blas gemm takes them as the conjugate transpose when
+// they are transposed in memory, and the other layouts use conjugated copies
+#[test]
+fn complex_mat_mul_conj() {
+    macro_rules! test_conj {
+        ($ty:ty, $re:ty) => {
+            let alpha = <$ty>::new(2., -1.);
+            let beta = <$ty>::new(-0.5, 3.);
+            let op = |a: ArrayView2<$ty>, conj| match conj {
+                Conj::No => a.to_owned(),
+                Conj::Yes => a.mapv(|x| x.conj()),
+            };
+            for &(m, k, n) in &[(4, 4, 4), (17, 15, 16), (33, 8, 65), (1, 9, 1), (64, 64, 1)] {
+                let (a, a_t) = (complex_mat::<$re>(m, k), complex_mat::<$re>(k, m));
+                let (b, b_t) = (complex_mat::<$re>(k, n), complex_mat::<$re>(n, k));
+                let (a_f, b_f) = (f_order(&a), f_order(&b));
+                let c0 = complex_mat::<$re>(m, n);
+                let conjs = [(Conj::No, Conj::Yes), (Conj::Yes, Conj::No), (Conj::Yes, Conj::Yes)];
+                for &(conj_a, conj_b) in &conjs {
+                    for lhs in &[a.view(), a_f.view(), a_t.t()] {
+                        for rhs in &[b.view(), b_f.view(), b_t.t()] {
+                            let answer = op(lhs.view(), conj_a).dot(&op(rhs.view(), conj_b));
+                            let answer = answer.mapv(|x| x * alpha) + c0.mapv(|x| x * beta);
+                            for &fc in &[false, true] {
+                                let mut c = if fc { f_order(&c0) } else { c0.clone() };
+                                general_mat_mul_conj(alpha, lhs, conj_a, rhs, conj_b, beta, &mut c);
+                                assert_eq!(c, answer);
+                            }
+                        }
+
+                        let x = b.column(0);
+                        let x_op = x.mapv(|x| if conj_b == Conj::Yes { x.conj() } else { x });
+                        let y0 = c0.column(0);
+                        let answer = op(lhs.view(), conj_a).dot(&x_op).mapv(|x| x * alpha)
+                            + y0.mapv(|x| x * beta);
+                        let mut y = y0.to_owned();
+                        general_mat_vec_mul_conj(alpha, lhs, conj_a, &x, conj_b, beta, &mut y);
+                        assert_eq!(y, answer);
+                    }
+                }
+            }
+        };
+    }
+    test_conj!(Complex32, f32);
+    test_conj!(Complex64, f64);
+}
+
+// The triangle `uplo` of `a`, with zeros elsewhere
+fn triangle_of(a: &Array2<f64>, uplo: Triangle) -> Array2<f64> {
+    Array::from_shape_fn(a.dim(), |(i, j)| match uplo {
+        Triangle::Upper if i <= j => a[[i, j]],
+        Triangle::Lower if i >= j => a[[i, j]],
+        _ => 0.,
+    })
+}
+
+// Mixed memory layouts of the operands, with the transposes passed to BLAS
+#[test]
+fn triangular_and_rank_updates() {
+    for &m in &[1, 4, 17, 40] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            for &(fa, fb) in &[(false, false), (true, false), (false, true), (true, true)] {
+                let layout = |f: bool, a: Array2<f64>| {
+                    if f {
+                        a.t().as_standard_layout().into_owned().reversed_axes()
+                    } else {
+                        a
+                    }
+                };
+                let a = layout(fa, range_mat64(m, m) / (m * m) as f64 + Array2::<f64>::eye(m));
+                let a_tri = triangle_of(&a, uplo);
+                let b0 = layout(fb, range_mat64(m, 3));
+
+                let mut b = b0.clone();
+                triangular_mat_mul(uplo, 2., &a, &mut b);
+                let answer = 2. * reference_mat_mul(&a_tri, &b0);
+                assert_relative_eq!(b, answer, epsilon = 1e-9, max_relative = 1e-9);
+
+                triangular_solve(uplo, &a, &mut b);
+                assert_relative_eq!(b, 2. * &b0, epsilon = 1e-9, max_relative = 1e-9);
+
+                let mut x = b0.column(1).to_owned();
+                triangular_solve(uplo, &a, &mut x);
+                let answer = b0.column(1);
+                assert_relative_eq!(a_tri.dot(&x), answer, epsilon = 1e-9, max_relative = 1e-9);
+
+                let mut c = a.clone();
+                symmetric_rank_k_update(uplo, 1., &b0, 1., &mut c);
+                let answer = reference_mat_mul(&b0, &b0.t()) + &a;
+                assert_relative_eq!(
+                    triangle_of(&c, uplo),
+                    triangle_of(&answer, uplo),
+                    epsilon = 1e-9,
+                    max_relative = 1e-9
+                );
+
+                let mut c = a.clone();
+                general_outer_add(1., &b0.column(0), &b0.column(2), &mut c);
+                let answer = &a + &(&b0.slice(s![.., 0..1]) * &b0.slice(s![.., 2..3]).t());
+                assert_relative_eq!(c, answer, epsilon = 1e-9, max_relative = 1e-9);
+            }
+        }
+    }
+}
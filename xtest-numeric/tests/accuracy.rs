@@ -15,7 +15,7 @@ use ndarray::{
     Data,
     LinalgScalar,
 };
-use ndarray::linalg::general_mat_mul;
+use ndarray::linalg::{eigh, expm, general_mat_mul, matrix_power, Triangle};
 
 use rand_distr::Normal;
 
@@ -263,3 +263,96 @@ fn accurate_mul_with_column_f64() {
         }
     }
 }
+
+// the sum of the Taylor series of the exponential, with extended range by squaring
+fn reference_expm(a: &Array2<f64>) -> Array2<f64> {
+    let norm = a.iter().map(|x| x.abs()).sum::<f64>();
+    let squarings = norm.log2().ceil().max(0.) as i32 + 1;
+    let a = a / 2f64.powi(squarings);
+    let mut term = Array::eye(a.nrows());
+    let mut sum = term.clone();
+    for k in 1..30 {
+        term = reference_mat_mul(&term, &a) / k as f64;
+        sum += &term;
+    }
+    for _ in 0..squarings {
+        sum = reference_mat_mul(&sum, &sum);
+    }
+    sum
+}
+
+#[test]
+fn accurate_expm_f64() {
+    let mut rng = SmallRng::from_entropy();
+    for i in 0..20 {
+        let n = rng.gen_range(1..60);
+        // norms from below the smallest Padé approximant to needing scaling
+        let scale = 10f64.powf(rng.gen_range(-3.0..1.0)) / (n as f64).sqrt();
+        let a = gen_f64(Ix2(n, n)) * scale;
+        let a = if i % 2 == 0 { a } else { a.reversed_axes() };
+        let norm = a.map(|x| x.abs()).sum_axis(Axis(0)).fold(0., |m: f64, &x| m.max(x));
+        println!("Testing size {} with 1-norm {}", n, norm);
+        // the error grows with the norm of A, and is relative to the norm of the result
+        let tol = 1e-13 * (1. + norm);
+        let e = expm(&a).unwrap();
+        let reference = reference_expm(&a);
+        let max = reference.fold(0., |m: f64, &x| m.max(x.abs()));
+        assert_abs_diff_eq!(e, reference, epsilon = tol * max);
+        // the exponential of −A is the inverse
+        let e_inv = expm(&-&a).unwrap();
+        let max_inv = e_inv.fold(0., |m: f64, &x| m.max(x.abs()));
+        assert_abs_diff_eq!(e.dot(&e_inv), Array::eye(n), epsilon = tol * max * max_inv);
+    }
+}
+
+#[test]
+fn accurate_expm_symmetric_f64() {
+    let mut rng = SmallRng::from_entropy();
+    for _ in 0..10 {
+        let n = rng.gen_range(1..100);
+        let b = gen_f64(Ix2(n, n));
+        let a = (&b + &b.t()) / (n as f64).sqrt();
+        println!("Testing size {}", n);
+        // exp(A) = V exp(Λ) Vᵀ
+        let (w, v) = eigh(&a, Triangle::Lower).unwrap();
+        let reference = (&v * &w.mapv(f64::exp)).dot(&v.t());
+        let e = expm(&a).unwrap();
+        assert_relative_eq!(e, reference, epsilon = 1e-12, max_relative = 1e-9);
+    }
+}
+
+#[test]
+fn accurate_expm_f32() {
+    let mut rng = SmallRng::from_entropy();
+    for _ in 0..10 {
+        let n = rng.gen_range(1..40);
+        let scale = 10f64.powf(rng.gen_range(-3.0..1.0)) / (n as f64).sqrt();
+        let a = gen(Ix2(n, n)) * scale as f32;
+        println!("Testing size {}", n);
+        let e = expm(&a).unwrap();
+        let reference = reference_expm(&a.mapv(f64::from)).mapv(|x| x as f32);
+        // the error is relative to the norm of the result
+        let max = reference.fold(0., |m: f32, &x| m.max(x.abs()));
+        assert_abs_diff_eq!(e, reference, epsilon = 1e-5 * max);
+    }
+}
+
+#[test]
+fn accurate_matrix_power_f64() {
+    let mut rng = SmallRng::from_entropy();
+    for _ in 0..10 {
+        let n = rng.gen_range(1..60);
+        // a well conditioned matrix with spectral radius near one
+        let a = Array2::<f64>::eye(n) + gen_f64(Ix2(n, n)) * (0.1 / (n as f64).sqrt());
+        let power = rng.gen_range(0..40);
+        println!("Testing size {} to the power {}", n, power);
+        let mut reference = Array::eye(n);
+        for _ in 0..power {
+            reference = reference_mat_mul(&reference, &a);
+        }
+        assert_relative_eq!(matrix_power(&a, power).unwrap(), reference,
+                            epsilon = 1e-10, max_relative = 1e-10);
+        let inverse = matrix_power(&a, -power).unwrap();
+        assert_abs_diff_eq!(inverse.dot(&reference), Array::eye(n), epsilon = 1e-9);
+    }
+}